@@ -0,0 +1,53 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use complex_proto::simd::Backend;
+use complex_proto::Complex;
+
+const LEN: usize = 1 << 12;
+const ROUNDS: u32 = 5000;
+
+fn time(name: &str, mut f: impl FnMut()) {
+    f();
+    let start: Instant = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    let per_round: Duration = start.elapsed() / ROUNDS;
+    println!("{:<28} {:>10.2?}  ({:.2} ns/element)", name, per_round, per_round.as_nanos() as f64 / LEN as f64);
+}
+
+fn main() {
+    let a: Vec<Complex> = (0..LEN).map(|i| Complex::new(i as f64 * 1e-3, 1.0 - i as f64 * 1e-4)).collect();
+    let b: Vec<Complex> = a.iter().map(|z| z.conj() + 0.5).collect();
+    let mut out: Vec<Complex> = vec![Complex::default(); LEN];
+
+    time("scalar mul", || {
+        for ((o, &x), &y) in out.iter_mut().zip(black_box(&a)).zip(black_box(&b)) {
+            *o = x * y;
+        }
+        black_box(&out);
+    });
+    time("scalar dot", || {
+        black_box(black_box(&a).iter().zip(black_box(&b)).map(|(&x, &y)| x * y).sum::<Complex>());
+    });
+    for backend in [Backend::Portable, Backend::Sse2, Backend::Avx2] {
+        if !backend.is_available() {
+            continue;
+        }
+        time(&format!("{:?} mul_slices", backend), || {
+            backend.mul_slices(black_box(&a), black_box(&b), &mut out);
+            black_box(&out);
+        });
+        time(&format!("{:?} mul_add", backend), || {
+            backend.mul_add(black_box(&a), black_box(&b), &mut out);
+            black_box(&out);
+        });
+        time(&format!("{:?} dot", backend), || {
+            black_box(backend.dot(black_box(&a), black_box(&b)));
+        });
+        time(&format!("{:?} norm_sq_sum", backend), || {
+            black_box(backend.norm_sq_sum(black_box(&a)));
+        });
+    }
+}
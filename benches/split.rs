@@ -0,0 +1,68 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use complex_proto::{Complex, SplitComplex};
+
+const LEN: usize = 1 << 16;
+const ROUNDS: u32 = 200;
+
+fn time(name: &str, mut f: impl FnMut()) {
+    f();
+    let start: Instant = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    let per_round: Duration = start.elapsed() / ROUNDS;
+    println!("{:<24} {:>10.2?}  ({:.2} ns/element)", name, per_round, per_round.as_nanos() as f64 / LEN as f64);
+}
+
+fn main() {
+    let a: Vec<Complex> = (0..LEN).map(|i| Complex::new(i as f64 * 1e-3, 1.0 - i as f64 * 1e-4)).collect();
+    let b: Vec<Complex> = a.iter().map(|z| z.conj() + 0.5).collect();
+    let (sa, sb): (SplitComplex, SplitComplex) = (SplitComplex::from(a.as_slice()), SplitComplex::from(b.as_slice()));
+    let mut out: Vec<Complex> = vec![Complex::default(); LEN];
+    let mut split: SplitComplex = sa.clone();
+
+    time("scalar mul", || {
+        out.copy_from_slice(&a);
+        for (o, &y) in out.iter_mut().zip(black_box(&b)) {
+            *o *= y;
+        }
+        black_box(&out);
+    });
+    time("split mul", || {
+        split.clone_from(&sa);
+        split *= black_box(&sb);
+        black_box(&split);
+    });
+    time("scalar add", || {
+        out.copy_from_slice(&a);
+        for (o, &y) in out.iter_mut().zip(black_box(&b)) {
+            *o += y;
+        }
+        black_box(&out);
+    });
+    time("split add", || {
+        split.clone_from(&sa);
+        split += black_box(&sb);
+        black_box(&split);
+    });
+    time("scalar div", || {
+        out.copy_from_slice(&a);
+        for (o, &y) in out.iter_mut().zip(black_box(&b)) {
+            *o /= y;
+        }
+        black_box(&out);
+    });
+    time("split div", || {
+        split.clone_from(&sa);
+        split /= black_box(&sb);
+        black_box(&split);
+    });
+    time("scalar norm", || {
+        black_box(a.iter().map(|z| z.norm()).collect::<Vec<f64>>());
+    });
+    time("split norm", || {
+        black_box(sa.norm());
+    });
+}
@@ -1,89 +1,614 @@
+#[allow(unexpected_cfgs, clippy::unused_unit)]
 mod complex {
-    use std::ops::{Neg, Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign};
-    use std::fmt;
-    #[derive(Copy, Clone, Default)]
-    pub struct Complex {
-        pub real: f64,
-        pub imag: f64,
+    use core::ops::{Neg, Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Rem, RemAssign};
+    use core::fmt;
+    use core::hash::{Hash, Hasher};
+    use core::cmp::Ordering;
+    use core::str::FromStr;
+    use core::iter::{Sum, Product};
+    use core::borrow::Borrow;
+    pub trait Scalar:
+        Copy + Default + PartialOrd + fmt::Display + fmt::Debug
+        + Neg<Output = Self>
+        + Add<Output = Self> + AddAssign
+        + Sub<Output = Self> + SubAssign
+        + Mul<Output = Self> + MulAssign
+    {
+        const ZERO: Self;
+        const ONE: Self;
+        // a * b - c * d and a * b + c * d, as used by complex multiplication
+        #[inline(always)]
+        fn diff_of_products(a: Self, b: Self, c: Self, d: Self) -> Self {
+            a * b - c * d
+        }
+        #[inline(always)]
+        fn sum_of_products(a: Self, b: Self, c: Self, d: Self) -> Self {
+            a * b + c * d
+        }
     }
     #[allow(dead_code)]
-    impl Complex {
-        pub const REAL_UNIT: Self = Self { real: 1.0_f64, imag: 0.0_f64 };
-        pub const IMAG_UNIT: Self = Self { real: 0.0_f64, imag: 1.0_f64 };
+    pub trait Float:
+        Scalar + Div<Output = Self> + DivAssign + Rem<Output = Self>
+        + Add<ComplexT<Self>, Output = ComplexT<Self>>
+        + Sub<ComplexT<Self>, Output = ComplexT<Self>>
+        + Mul<ComplexT<Self>, Output = ComplexT<Self>>
+        + Div<ComplexT<Self>, Output = ComplexT<Self>>
+    {
+        type Bits: Copy + Eq + Hash;
+        const HALF: Self;
+        const TWO: Self;
+        const INFINITY: Self;
+        const NAN: Self;
+        const MAX: Self;
+        const MIN_POSITIVE: Self;
+        const EPSILON: Self;
+        const PI: Self;
+        const FRAC_PI_2: Self;
+        const FRAC_PI_4: Self;
+        const LOG2_E: Self;
+        const LOG10_E: Self;
+        const LN_2: Self;
+        fn from_i32(n: i32) -> Self;
+        fn is_nan(self) -> bool;
+        fn is_infinite(self) -> bool;
+        fn is_finite(self) -> bool;
+        fn abs(self) -> Self;
+        fn max(self, other: Self) -> Self;
+        fn copysign(self, sign: Self) -> Self;
+        fn to_bits(self) -> Self::Bits;
+        fn to_ordinal(self) -> i64;
+        fn total_cmp(&self, other: &Self) -> Ordering;
+        fn hypot(self, other: Self) -> Self;
+        fn atan2(self, other: Self) -> Self;
+        fn exp(self) -> Self;
+        fn ln(self) -> Self;
+        fn ln_1p(self) -> Self;
+        fn exp_m1(self) -> Self;
+        fn mul_add(self, a: Self, b: Self) -> Self;
+        fn log2(self) -> Self;
+        fn log10(self) -> Self;
+        fn log(self, base: Self) -> Self;
+        fn sqrt(self) -> Self;
+        fn powi(self, exp: i32) -> Self;
+        fn powf(self, exp: Self) -> Self;
+        fn sin(self) -> Self;
+        fn cos(self) -> Self;
+        fn tan(self) -> Self;
+        fn sinh(self) -> Self;
+        fn cosh(self) -> Self;
+        fn tanh(self) -> Self;
+        fn asin(self) -> Self;
+        fn acos(self) -> Self;
+        fn atanh(self) -> Self;
+    }
+    #[cfg(any(feature = "std", not(feature = "libm")))]
+    macro_rules! math {
+        ($t:ident, $method:ident / $f32:ident / $f64:ident ($($arg:expr),*)) => {
+            <$t>::$method($($arg),*)
+        };
+    }
+    #[cfg(all(not(feature = "std"), feature = "libm"))]
+    macro_rules! math {
+        (f32, $method:ident / $f32:ident / $f64:ident ($($arg:expr),*)) => {
+            libm::$f32($($arg),*)
+        };
+        (f64, $method:ident / $f32:ident / $f64:ident ($($arg:expr),*)) => {
+            libm::$f64($($arg),*)
+        };
+    }
+    macro_rules! impl_float {
+        ($t:ident, $bits:ident, $ibits:ident) => {
+            impl Scalar for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                #[cfg(feature = "accurate-mul")]
+                #[inline(always)]
+                fn diff_of_products(a: Self, b: Self, c: Self, d: Self) -> Self {
+                    ComplexT::<$t>::fma_diff_of_products(a, b, c, d)
+                }
+                #[cfg(feature = "accurate-mul")]
+                #[inline(always)]
+                fn sum_of_products(a: Self, b: Self, c: Self, d: Self) -> Self {
+                    ComplexT::<$t>::fma_sum_of_products(a, b, c, d)
+                }
+            }
+            impl Float for $t {
+                type Bits = $bits;
+                const HALF: Self = 0.5;
+                const TWO: Self = 2.0;
+                const INFINITY: Self = <$t>::INFINITY;
+                const NAN: Self = <$t>::NAN;
+                const MAX: Self = <$t>::MAX;
+                const MIN_POSITIVE: Self = <$t>::MIN_POSITIVE;
+                const EPSILON: Self = <$t>::EPSILON;
+                const PI: Self = core::$t::consts::PI;
+                const FRAC_PI_2: Self = core::$t::consts::FRAC_PI_2;
+                const FRAC_PI_4: Self = core::$t::consts::FRAC_PI_4;
+                const LOG2_E: Self = core::$t::consts::LOG2_E;
+                const LOG10_E: Self = core::$t::consts::LOG10_E;
+                const LN_2: Self = core::$t::consts::LN_2;
+                #[inline(always)]
+                fn from_i32(n: i32) -> Self {
+                    n as $t
+                }
+                #[inline(always)]
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+                #[inline(always)]
+                fn is_infinite(self) -> bool {
+                    <$t>::is_infinite(self)
+                }
+                #[inline(always)]
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+                #[inline(always)]
+                fn abs(self) -> Self {
+                    math!($t, abs / fabsf / fabs (self))
+                }
+                #[inline(always)]
+                fn max(self, other: Self) -> Self {
+                    <$t>::max(self, other)
+                }
+                #[inline(always)]
+                fn copysign(self, sign: Self) -> Self {
+                    math!($t, copysign / copysignf / copysign (self, sign))
+                }
+                #[inline(always)]
+                fn to_bits(self) -> Self::Bits {
+                    <$t>::to_bits(self)
+                }
+                #[inline(always)]
+                fn to_ordinal(self) -> i64 {
+                    let bits: $ibits = <$t>::to_bits(self) as $ibits;
+                    (if bits < 0 { $ibits::MIN - bits } else { bits }) as i64
+                }
+                #[inline(always)]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$t>::total_cmp(self, other)
+                }
+                #[inline(always)]
+                fn hypot(self, other: Self) -> Self {
+                    math!($t, hypot / hypotf / hypot (self, other))
+                }
+                #[inline(always)]
+                fn atan2(self, other: Self) -> Self {
+                    math!($t, atan2 / atan2f / atan2 (self, other))
+                }
+                #[inline(always)]
+                fn exp(self) -> Self {
+                    math!($t, exp / expf / exp (self))
+                }
+                #[inline(always)]
+                fn ln(self) -> Self {
+                    math!($t, ln / logf / log (self))
+                }
+                #[inline(always)]
+                fn ln_1p(self) -> Self {
+                    math!($t, ln_1p / log1pf / log1p (self))
+                }
+                #[inline(always)]
+                fn exp_m1(self) -> Self {
+                    math!($t, exp_m1 / expm1f / expm1 (self))
+                }
+                #[inline(always)]
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    math!($t, mul_add / fmaf / fma (self, a, b))
+                }
+                #[inline(always)]
+                fn log2(self) -> Self {
+                    math!($t, log2 / log2f / log2 (self))
+                }
+                #[inline(always)]
+                fn log10(self) -> Self {
+                    math!($t, log10 / log10f / log10 (self))
+                }
+                #[inline(always)]
+                fn log(self, base: Self) -> Self {
+                    math!($t, ln / logf / log (self)) / math!($t, ln / logf / log (base))
+                }
+                #[inline(always)]
+                fn sqrt(self) -> Self {
+                    math!($t, sqrt / sqrtf / sqrt (self))
+                }
+                #[cfg(any(feature = "std", not(feature = "libm")))]
+                #[inline(always)]
+                fn powi(self, exp: i32) -> Self {
+                    <$t>::powi(self, exp)
+                }
+                #[cfg(all(not(feature = "std"), feature = "libm"))]
+                #[inline(always)]
+                fn powi(self, exp: i32) -> Self {
+                    math!($t, powf / powf / pow (self, exp as $t))
+                }
+                #[inline(always)]
+                fn powf(self, exp: Self) -> Self {
+                    math!($t, powf / powf / pow (self, exp))
+                }
+                #[inline(always)]
+                fn sin(self) -> Self {
+                    math!($t, sin / sinf / sin (self))
+                }
+                #[inline(always)]
+                fn cos(self) -> Self {
+                    math!($t, cos / cosf / cos (self))
+                }
+                #[inline(always)]
+                fn tan(self) -> Self {
+                    math!($t, tan / tanf / tan (self))
+                }
+                #[inline(always)]
+                fn sinh(self) -> Self {
+                    math!($t, sinh / sinhf / sinh (self))
+                }
+                #[inline(always)]
+                fn cosh(self) -> Self {
+                    math!($t, cosh / coshf / cosh (self))
+                }
+                #[inline(always)]
+                fn tanh(self) -> Self {
+                    math!($t, tanh / tanhf / tanh (self))
+                }
+                #[inline(always)]
+                fn asin(self) -> Self {
+                    math!($t, asin / asinf / asin (self))
+                }
+                #[inline(always)]
+                fn acos(self) -> Self {
+                    math!($t, acos / acosf / acos (self))
+                }
+                #[inline(always)]
+                fn atanh(self) -> Self {
+                    math!($t, atanh / atanhf / atanh (self))
+                }
+            }
+        };
+    }
+    impl_float!(f32, u32, i32);
+    impl_float!(f64, u64, i64);
+    impl Scalar for i64 {
+        const ZERO: Self = 0;
+        const ONE: Self = 1;
+    }
+    #[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename = "Complex"))]
+    #[repr(C)]
+    pub struct ComplexT<T> {
+        pub real: T,
+        pub imag: T,
+    }
+    // not `Complex<T = f64>`: a default type parameter does not drive inference, so
+    // `Complex::new(1.0, 2.0).abs()` would need an annotation
+    #[allow(dead_code)]
+    pub type Complex = ComplexT<f64>;
+    #[allow(dead_code)]
+    pub type Complex32 = ComplexT<f32>;
+    #[allow(dead_code)]
+    pub type Complex64 = ComplexT<f64>;
+    #[allow(dead_code)]
+    pub type GaussianInt = ComplexT<i64>;
+    #[allow(dead_code)]
+    impl<T: Scalar> ComplexT<T> {
+        pub const REAL_UNIT: Self = Self { real: T::ONE, imag: T::ZERO };
+        pub const IMAG_UNIT: Self = Self { real: T::ZERO, imag: T::ONE };
         #[inline(always)]
-        pub fn new(real: f64, imag: f64) -> Self {
+        pub fn new(real: T, imag: T) -> Self {
             Self { real, imag }
         }
         #[inline(always)]
-        pub fn with_real(real: f64) -> Self {
-            Self::new(real, 0.0_f64)
+        pub fn with_real(real: T) -> Self {
+            Self::new(real, T::ZERO)
+        }
+        #[inline(always)]
+        pub fn with_imag(imag: T) -> Self {
+            Self::new(T::ZERO, imag)
+        }
+        // For `GaussianInt` this overflows once re^2 + im^2 passes i64::MAX; `norm_wide` is exact.
+        #[inline(always)]
+        pub fn norm(self) -> T {
+            self.real * self.real + self.imag * self.imag
+        }
+        #[inline(always)]
+        pub fn conj(self) -> Self {
+            Self::new(self.real, -self.imag)
         }
+    }
+    // `#[repr(C)]` with two fields of the same type: `ComplexT<T>` has the size and alignment of `[T; 2]`.
+    #[allow(dead_code)]
+    impl<T: Scalar> ComplexT<T> {
         #[inline(always)]
-        pub fn with_imag(imag: f64) -> Self {
-            Self::new(0.0_f64, imag)
+        pub fn as_interleaved(slice: &[Self]) -> &[T] {
+            unsafe { core::slice::from_raw_parts(slice.as_ptr().cast::<T>(), slice.len() * 2) }
         }
         #[inline(always)]
-        pub fn abs(self) -> f64 {
+        pub fn as_interleaved_mut(slice: &mut [Self]) -> &mut [T] {
+            unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast::<T>(), slice.len() * 2) }
+        }
+        #[inline(always)]
+        pub fn from_interleaved(slice: &[T]) -> Option<&[Self]> {
+            if slice.len() % 2 != 0 {
+                return None;
+            }
+            Some(unsafe { core::slice::from_raw_parts(slice.as_ptr().cast::<Self>(), slice.len() / 2) })
+        }
+        #[inline(always)]
+        pub fn from_interleaved_mut(slice: &mut [T]) -> Option<&mut [Self]> {
+            if slice.len() % 2 != 0 {
+                return None;
+            }
+            Some(unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast::<Self>(), slice.len() / 2) })
+        }
+    }
+    #[allow(dead_code)]
+    impl<T: Float> ComplexT<T> {
+        #[inline(always)]
+        pub fn abs(self) -> T {
             self.real.hypot(self.imag)
         }
         #[inline(always)]
-        pub fn arg(self) -> f64 {
+        pub fn arg(self) -> T {
             self.imag.atan2(self.real)
         }
         #[inline(always)]
-        pub fn norm(self) -> f64 {
-            self.real * self.real + self.imag * self.imag
+        pub fn from_polar(r: T, theta: T) -> Self {
+            Self::new(r * theta.cos(), r * theta.sin())
         }
         #[inline(always)]
-        pub fn conj(self) -> Self {
-            Self::new(self.real, -self.imag)
+        pub fn to_polar(self) -> Polar<T> {
+            Polar::new(self.abs(), self.arg())
         }
         #[inline(always)]
-        pub fn exp(self) -> Self {
-            let exp_real: f64 = self.real.exp();
+        pub fn recip(self) -> Self {
+            T::ONE / self
+        }
+        // each component within 1.5 ulp, even when the products cancel
+        #[inline(always)]
+        pub fn mul_accurate(self, other: Self) -> Self {
             Self::new(
-                exp_real * self.imag.cos(),
-                exp_real * self.imag.sin(),
+                Self::fma_diff_of_products(self.real, other.real, self.imag, other.imag),
+                Self::fma_sum_of_products(self.imag, other.real, self.real, other.imag),
             )
         }
         #[inline(always)]
+        pub fn is_nan(self) -> bool {
+            self.real.is_nan() || self.imag.is_nan()
+        }
+        #[inline(always)]
+        pub fn is_infinite(self) -> bool {
+            self.real.is_infinite() || self.imag.is_infinite()
+        }
+        #[inline(always)]
+        pub fn is_finite(self) -> bool {
+            self.real.is_finite() && self.imag.is_finite()
+        }
+        #[inline(always)]
+        pub fn approx_eq(self, other: Self, abs_tol: T, rel_tol: T) -> bool {
+            if self == other {
+                return true;
+            }
+            let diff: T = (self - other).abs();
+            diff <= abs_tol.max(rel_tol * self.abs().max(other.abs()))
+        }
+        #[inline(always)]
+        pub fn ulp_distance(self, other: Self) -> u64 {
+            if self.is_nan() || other.is_nan() {
+                return u64::MAX;
+            }
+            let real: u64 = (self.real.to_ordinal() as i128 - other.real.to_ordinal() as i128).unsigned_abs() as u64;
+            let imag: u64 = (self.imag.to_ordinal() as i128 - other.imag.to_ordinal() as i128).unsigned_abs() as u64;
+            real.max(imag)
+        }
+        #[inline(always)]
+        fn mul_i(self) -> Self {
+            Self::new(-self.imag, self.real)
+        }
+        #[inline(always)]
+        pub(crate) fn mul_neg_i(self) -> Self {
+            Self::new(self.imag, -self.real)
+        }
+        #[inline(always)]
+        fn exp_mul(x: T, factor: T) -> T {
+            let exp_x: T = x.exp();
+            if exp_x.is_infinite() && x.is_finite() {
+                let exp_half: T = (x * T::HALF).exp();
+                exp_half * factor * exp_half
+            } else {
+                exp_x * factor
+            }
+        }
+        #[inline(always)]
+        fn sinh_cosh_mul(x: T, sinh_factor: T, cosh_factor: T) -> (T, T) {
+            let cosh_x: T = x.cosh();
+            if cosh_x.is_infinite() && x.is_finite() {
+                let exp_half: T = (x.abs() * T::HALF).exp();
+                let sinh_factor: T = if x < T::ZERO { -sinh_factor } else { sinh_factor };
+                (
+                    exp_half * (sinh_factor * T::HALF) * exp_half,
+                    exp_half * (cosh_factor * T::HALF) * exp_half,
+                )
+            } else {
+                (x.sinh() * sinh_factor, cosh_x * cosh_factor)
+            }
+        }
+        #[inline]
+        pub fn exp(self) -> Self {
+            let (x, y): (T, T) = (self.real, self.imag);
+            if y == T::ZERO {
+                return Self::new(x.exp(), y);
+            }
+            if x.is_infinite() {
+                if !y.is_finite() {
+                    return if x > T::ZERO {
+                        Self::new(x, T::NAN)
+                    } else {
+                        Self::new(T::ZERO, T::ZERO)
+                    };
+                }
+            } else if !y.is_finite() {
+                return Self::new(T::NAN, T::NAN);
+            }
+            Self::new(
+                Self::exp_mul(x, y.cos()),
+                Self::exp_mul(x, y.sin()),
+            )
+        }
+        // Near the unit circle the real part is 0.5 * ln_1p(|z|^2 - 1) with |z|^2 - 1 summed from
+        // error-free products, so it stays within a few ulps relative to its own size instead of
+        // the absolute error of ln(|z|).
+        #[inline]
         pub fn ln(self) -> Self {
+            let (ax, ay): (T, T) = (self.real.abs(), self.imag.abs());
+            let (a, b): (T, T) = if ax >= ay { (ax, ay) } else { (ay, ax) };
+            if a >= T::HALF && a <= T::TWO {
+                return Self::new(Self::two_u_plus_norm(a - T::ONE, b).ln_1p() * T::HALF, self.arg());
+            }
             Self::new(self.abs().ln(), self.arg())
         }
+        // ln(1 + z) without forming 1 + z for |z| <= 1 and x >= -1/2 (below that 1 + x is exact and
+        // ln is used directly); error within a few ulps per component, except where Re ln(1 + z)
+        // itself cancels, i.e. 2x + x^2 + y^2 is below ulp(x^2) or ulp(y^2).
+        #[inline]
+        pub fn ln_1p(self) -> Self {
+            let (x, y): (T, T) = (self.real, self.imag);
+            if !(x.abs() <= T::ONE && y.abs() <= T::ONE) || x < -T::HALF {
+                return Self::new(T::ONE + x, y).ln();
+            }
+            Self::new(Self::two_u_plus_norm(x, y).ln_1p() * T::HALF, y.atan2(T::ONE + x))
+        }
+        // exp(z) - 1 with Re = exp_m1(x) cos(y) - 2 sin^2(y / 2) for |x| <= 1; error within a few ulps
+        // per component, except where those two terms cancel (x close to y^2 / 2).
+        #[inline]
+        pub fn exp_m1(self) -> Self {
+            let (x, y): (T, T) = (self.real, self.imag);
+            if !(x.abs() <= T::ONE && y.is_finite()) {
+                return self.exp() - T::ONE;
+            }
+            let half_sin: T = (y * T::HALF).sin();
+            Self::new(x.exp_m1() * y.cos() - T::TWO * half_sin * half_sin, x.exp() * y.sin())
+        }
+        #[inline(always)]
+        fn two_sum(a: T, b: T) -> (T, T) {
+            let s: T = a + b;
+            let bb: T = s - a;
+            (s, (a - (s - bb)) + (b - bb))
+        }
+        #[inline(always)]
+        fn two_prod(a: T, b: T) -> (T, T) {
+            let p: T = a * b;
+            (p, a.mul_add(b, -p))
+        }
+        // Kahan: the rounding error of c * d is recovered exactly and added back
+        #[inline(always)]
+        fn fma_diff_of_products(a: T, b: T, c: T, d: T) -> T {
+            let w: T = c * d;
+            let e: T = (-c).mul_add(d, w);
+            a.mul_add(b, -w) + e
+        }
+        #[inline(always)]
+        fn fma_sum_of_products(a: T, b: T, c: T, d: T) -> T {
+            let w: T = c * d;
+            let e: T = c.mul_add(d, -w);
+            a.mul_add(b, w) + e
+        }
+        // |1 + u + iv|^2 - 1 = 2u + u^2 + v^2
+        #[inline]
+        fn two_u_plus_norm(u: T, v: T) -> T {
+            let (uh, ul): (T, T) = Self::two_prod(u, u);
+            let (vh, vl): (T, T) = Self::two_prod(v, v);
+            let (s1, e1): (T, T) = Self::two_sum(T::TWO * u, vh);
+            let (s2, e2): (T, T) = Self::two_sum(s1, uh);
+            s2 + (e1 + e2 + ul + vl)
+        }
+        // scaled from `ln` so that every logarithm keeps its accuracy near the unit circle
         #[inline(always)]
         pub fn log2(self) -> Self {
-            Self::new(self.abs().log2(), self.arg())
+            self.ln() * T::LOG2_E
         }
         #[inline(always)]
         pub fn log10(self) -> Self {
-            Self::new(self.abs().log10(), self.arg())
+            self.ln() * T::LOG10_E
         }
-        #[inline(always)]
-        pub fn log(self, base: f64) -> Self {
-            Self::new(self.abs().log(base), self.arg())
+        #[inline]
+        pub fn log(self, base: T) -> Self {
+            if base < T::ZERO {
+                return self.log_complex(Self::with_real(base));
+            }
+            self.ln() / base.ln()
         }
         #[inline(always)]
+        pub fn log_complex(self, base: Self) -> Self {
+            self.ln() / base.ln()
+        }
+        #[inline]
         pub fn sqrt(self) -> Self {
-            let abs_sqrt: f64 = self.abs().sqrt();
-            let arg_half: f64 = self.arg() * 0.5_f64;
-            Self::new(
-                abs_sqrt * arg_half.cos(),
-                abs_sqrt * arg_half.sin(),
-            )
+            let (x, y): (T, T) = (self.real, self.imag);
+            if x == T::ZERO && y == T::ZERO {
+                return Self::new(T::ZERO, y);
+            }
+            if y.is_infinite() {
+                return Self::new(T::INFINITY, y);
+            }
+            if x.is_nan() {
+                return Self::new(x, T::NAN);
+            }
+            if x.is_infinite() {
+                let zero: T = if y.is_nan() { y } else { T::ZERO.copysign(y) };
+                return if x < T::ZERO {
+                    Self::new(zero.abs(), x.abs().copysign(y))
+                } else {
+                    Self::new(x, zero)
+                };
+            }
+            if y.is_nan() {
+                return Self::new(y, y);
+            }
+            let (mut x, mut y): (T, T) = (x, y);
+            let mut scale: T = T::ONE;
+            let huge: T = T::MAX * T::HALF * T::HALF;
+            let tiny: T = T::MIN_POSITIVE / T::EPSILON;
+            if x.abs() >= huge || y.abs() >= huge {
+                x *= T::HALF * T::HALF;
+                y *= T::HALF * T::HALF;
+                scale = T::TWO;
+            } else if x.abs() < tiny && y.abs() < tiny {
+                let up: T = T::ONE / (T::EPSILON * T::EPSILON);
+                x *= up;
+                y *= up;
+                scale = T::EPSILON;
+            }
+            let t: T = ((x.abs() + x.hypot(y)) * T::HALF).sqrt();
+            if x >= T::ZERO {
+                Self::new(t * scale, y / (T::TWO * t) * scale)
+            } else {
+                Self::new(y.abs() / (T::TWO * t) * scale, t.copysign(y) * scale)
+            }
         }
         #[inline(always)]
         pub fn powi(self, exp: i32) -> Self {
-            let abs_pow: f64 = self.abs().powi(exp);
-            let arg: f64 = self.arg();
-            Self::new(
-                abs_pow * (exp as f64 * arg).cos(),
-                abs_pow * (exp as f64 * arg).sin(),
-            )
+            let mut base: Self = self;
+            let mut n: u32 = exp.unsigned_abs();
+            let mut acc: Self = Self::REAL_UNIT;
+            while n > 0 {
+                if n & 1 == 1 {
+                    acc *= base;
+                }
+                n >>= 1;
+                if n > 0 {
+                    base *= base;
+                }
+            }
+            if exp < 0 {
+                acc.recip()
+            } else {
+                acc
+            }
         }
         #[inline(always)]
-        pub fn powf(self, exp: f64) -> Self {
-            let abs_pow: f64 = self.abs().powf(exp);
-            let arg: f64 = self.arg();
+        pub fn powf(self, exp: T) -> Self {
+            let abs_pow: T = self.abs().powf(exp);
+            let arg: T = self.arg();
             Self::new(
                 abs_pow * (exp * arg).cos(),
                 abs_pow * (exp * arg).sin(),
@@ -91,76 +616,411 @@ mod complex {
         }
         #[inline(always)]
         pub fn pow(self, exp: Self) -> Self {
-            let ln: Self = self.ln();
-            Self::new(
-                (exp.real * ln.real - exp.imag * ln.imag).exp(),
-                exp.real * ln.imag + exp.imag * ln.real,
-            )
+            if self.real == T::ZERO && self.imag == T::ZERO {
+                return if exp.real == T::ZERO && exp.imag == T::ZERO {
+                    Self::REAL_UNIT
+                } else if exp.real > T::ZERO {
+                    Self::new(T::ZERO, T::ZERO)
+                } else if exp.imag == T::ZERO {
+                    Self::with_real(T::INFINITY)
+                } else {
+                    Self::new(T::NAN, T::NAN)
+                };
+            }
+            (exp * self.ln()).exp()
         }
         #[inline(always)]
         pub fn sin(self) -> Self {
-            Self::new(
-                self.real.sin() * self.imag.cosh(),
-                self.real.cos() * self.imag.sinh(),
-            )
+            self.mul_i().sinh().mul_neg_i()
         }
         #[inline(always)]
         pub fn cos(self) -> Self {
-            Self::new(
-                self.real.cos() * self.imag.cosh(),
-                -(self.real.sin() * self.imag.sinh()),
-            )
+            self.mul_i().cosh()
         }
         #[inline(always)]
         pub fn tan(&self) -> Self {
-            self.sin() / self.cos()
+            self.mul_i().tanh().mul_neg_i()
         }
-        #[inline(always)]
+        #[inline]
         pub fn sinh(self) -> Self {
-            Self::new(
-                self.real.sinh() * self.imag.cos(),
-                self.real.cosh() * self.imag.sin(),
-            )
+            let (x, y): (T, T) = (self.real, self.imag);
+            if y == T::ZERO {
+                return Self::new(x.sinh(), y);
+            }
+            if x == T::ZERO && !y.is_finite() {
+                return Self::new(x, T::NAN);
+            }
+            if x.is_infinite() {
+                return if y.is_finite() {
+                    Self::new(x * y.cos(), T::INFINITY * y.sin())
+                } else {
+                    Self::new(x, T::NAN)
+                };
+            }
+            let (real, imag): (T, T) = Self::sinh_cosh_mul(x, y.cos(), y.sin());
+            Self::new(real, imag)
         }
-        #[inline(always)]
+        #[inline]
         pub fn cosh(self) -> Self {
-            Self::new(
-                self.real.cosh() * self.imag.cos(),
-                self.real.sinh() * self.imag.sin(),
-            )
+            let (x, y): (T, T) = (self.real, self.imag);
+            if y == T::ZERO {
+                return Self::new(x.cosh(), T::ZERO.copysign(x) * y);
+            }
+            if x == T::ZERO && !y.is_finite() {
+                return Self::new(T::NAN, x);
+            }
+            if x.is_infinite() {
+                return if y.is_finite() {
+                    Self::new(T::INFINITY * y.cos(), x * y.sin())
+                } else {
+                    Self::new(T::INFINITY, T::NAN)
+                };
+            }
+            let (imag, real): (T, T) = Self::sinh_cosh_mul(x, y.sin(), y.cos());
+            Self::new(real, imag)
         }
-        #[inline(always)]
+        #[inline]
         pub fn tanh(self) -> Self {
-            self.sinh() / self.cosh()
+            let (x, y): (T, T) = (self.real, self.imag);
+            if x.is_infinite() {
+                let imag_sign: T = if y.is_finite() { (y * T::TWO).sin() } else { y };
+                return Self::new(T::ONE.copysign(x), T::ZERO.copysign(imag_sign));
+            }
+            if y == T::ZERO {
+                return Self::new(x.tanh(), y);
+            }
+            if !y.is_finite() || x.is_nan() {
+                return Self::new(if x == T::ZERO { x } else { T::NAN }, T::NAN);
+            }
+            if x.abs() >= (T::ONE / T::EPSILON).ln() * T::HALF + T::TWO {
+                let exp_mx: T = (-x.abs()).exp();
+                return Self::new(T::ONE.copysign(x), T::from_i32(4) * y.sin() * y.cos() * exp_mx * exp_mx);
+            }
+            let t: T = y.tan();
+            let beta: T = T::ONE + t * t;
+            let s: T = x.sinh();
+            let rho: T = (T::ONE + s * s).sqrt();
+            let denom: T = T::ONE + beta * s * s;
+            Self::new(beta * rho * s / denom, t / denom)
         }
         #[inline(always)]
         pub fn asin(self) -> Self {
-            -Self::IMAG_UNIT * (Self::IMAG_UNIT * self + (-(self * self) + 1.0_f64).sqrt()).ln()
+            self.mul_i().asinh().mul_neg_i()
         }
-        #[inline(always)]
+        #[inline]
         pub fn acos(self) -> Self {
-            -Self::IMAG_UNIT * (self + Self::IMAG_UNIT * (-(self * self) + 1.0_f64).sqrt()).ln()
+            let (x, y): (T, T) = (self.real, self.imag);
+            if x.is_nan() {
+                return Self::new(T::NAN, if y.is_infinite() { -y } else { T::NAN });
+            }
+            if y.is_nan() {
+                return if x.is_infinite() {
+                    Self::new(T::NAN, T::INFINITY)
+                } else if x == T::ZERO {
+                    Self::new(T::FRAC_PI_2, T::NAN)
+                } else {
+                    Self::new(T::NAN, T::NAN)
+                };
+            }
+            if y.is_infinite() {
+                return if x.is_infinite() {
+                    Self::new(if x > T::ZERO { T::FRAC_PI_4 } else { T::PI - T::FRAC_PI_4 }, -y)
+                } else {
+                    Self::new(T::FRAC_PI_2, -y)
+                };
+            }
+            if x.is_infinite() {
+                return Self::new(if x > T::ZERO { T::ZERO } else { T::PI }, -T::INFINITY.copysign(y));
+            }
+            let (ax, ay): (T, T) = (x.abs(), y.abs());
+            let (neg_x, neg_y): (bool, bool) = (T::ONE.copysign(x) < T::ZERO, T::ONE.copysign(y) < T::ZERO);
+            if ax > T::ONE / T::EPSILON || ay > T::ONE / T::EPSILON {
+                let ry: T = Self::ln_hypot(ax, ay) + T::LN_2;
+                return Self::new(y.atan2(x).abs(), if neg_y { ry } else { -ry });
+            }
+            if x == T::ONE && y == T::ZERO {
+                return Self::new(T::ZERO, -y);
+            }
+            let tiny: T = Self::sqrt_eps_times(6) / T::from_i32(4);
+            if ax < tiny && ay < tiny {
+                return Self::new(T::FRAC_PI_2 - x, -y);
+            }
+            let (ry, b, sqrt_a2mx2, new_x): (T, Option<T>, T, T) = Self::hull_fairgrieve_tang(ay, ax);
+            let rx: T = match b {
+                Some(b) => (if neg_x { -b } else { b }).acos(),
+                None => sqrt_a2mx2.atan2(if neg_x { -new_x } else { new_x }),
+            };
+            Self::new(rx, if neg_y { ry } else { -ry })
         }
         #[inline(always)]
         pub fn atan(self) -> Self {
-            Self::IMAG_UNIT * Self::with_real(0.5_f64) * (
-                (-(Self::IMAG_UNIT * self) + 1.0_f64) / (Self::IMAG_UNIT * self + 1.0_f64)
-            ).ln()
+            self.mul_i().atanh().mul_neg_i()
         }
-        #[inline(always)]
+        #[inline]
         pub fn asinh(self) -> Self {
-            (self + (self * self + 1.0_f64).sqrt()).ln()
+            let (x, y): (T, T) = (self.real, self.imag);
+            if x.is_nan() {
+                return if y == T::ZERO {
+                    self
+                } else if y.is_infinite() {
+                    Self::new(T::INFINITY, T::NAN)
+                } else {
+                    Self::new(T::NAN, T::NAN)
+                };
+            }
+            if y.is_nan() {
+                return Self::new(if x.is_infinite() { x } else { T::NAN }, T::NAN);
+            }
+            if y.is_infinite() {
+                return if x.is_infinite() {
+                    Self::new(x, T::FRAC_PI_4.copysign(y))
+                } else {
+                    Self::new(T::INFINITY.copysign(x), T::FRAC_PI_2.copysign(y))
+                };
+            }
+            if x.is_infinite() {
+                return Self::new(x, T::ZERO.copysign(y));
+            }
+            if x == T::ZERO && y == T::ZERO {
+                return self;
+            }
+            let (ax, ay): (T, T) = (x.abs(), y.abs());
+            if ax > T::ONE / T::EPSILON || ay > T::ONE / T::EPSILON {
+                return Self::new((Self::ln_hypot(ax, ay) + T::LN_2).copysign(x), ay.atan2(ax).copysign(y));
+            }
+            let tiny: T = Self::sqrt_eps_times(6) / T::from_i32(4);
+            if ax < tiny && ay < tiny {
+                return self;
+            }
+            let (rx, b, sqrt_a2my2, new_y): (T, Option<T>, T, T) = Self::hull_fairgrieve_tang(ax, ay);
+            let ry: T = match b {
+                Some(b) => b.asin(),
+                None => new_y.atan2(sqrt_a2my2),
+            };
+            Self::new(rx.copysign(x), ry.copysign(y))
         }
-        #[inline(always)]
+        #[inline]
         pub fn acosh(self) -> Self {
-            (self + (self * self - 1.0_f64).sqrt()).ln()
+            let (x, y): (T, T) = (self.real, self.imag);
+            if x.is_nan() || y.is_nan() {
+                return if x.is_infinite() || y.is_infinite() {
+                    Self::new(T::INFINITY, T::NAN)
+                } else {
+                    Self::new(T::NAN, T::NAN)
+                };
+            }
+            if y.is_infinite() {
+                return if x.is_infinite() {
+                    let angle: T = if x > T::ZERO { T::FRAC_PI_4 } else { T::PI - T::FRAC_PI_4 };
+                    Self::new(T::INFINITY, angle.copysign(y))
+                } else {
+                    Self::new(T::INFINITY, T::FRAC_PI_2.copysign(y))
+                };
+            }
+            if x.is_infinite() {
+                return Self::new(T::INFINITY, if x > T::ZERO { T::ZERO } else { T::PI }.copysign(y));
+            }
+            if x == T::ZERO && y == T::ZERO {
+                return Self::new(T::ZERO, T::FRAC_PI_2.copysign(y));
+            }
+            let w: Self = self.acos();
+            Self::new(w.imag.abs(), w.real.copysign(y))
         }
-        #[inline(always)]
+        #[inline]
         pub fn atanh(self) -> Self {
-            Self::with_real(0.5_f64) * ((self + 1.0_f64) / (-self + 1.0_f64)).ln()
+            let (x, y): (T, T) = (self.real, self.imag);
+            if x.is_nan() {
+                return if y.is_infinite() {
+                    Self::new(T::ZERO.copysign(x), T::FRAC_PI_2.copysign(y))
+                } else {
+                    Self::new(T::NAN, T::NAN)
+                };
+            }
+            if y.is_nan() {
+                return if x.is_infinite() {
+                    Self::new(T::ZERO.copysign(x), T::NAN)
+                } else if x == T::ZERO {
+                    self
+                } else {
+                    Self::new(T::NAN, T::NAN)
+                };
+            }
+            if x.is_infinite() || y.is_infinite() {
+                return Self::new(T::ZERO.copysign(x), T::FRAC_PI_2.copysign(y));
+            }
+            if y == T::ZERO && x.abs() == T::ONE {
+                return Self::new(T::INFINITY.copysign(x), y);
+            }
+            if x == T::ZERO && y == T::ZERO {
+                return self;
+            }
+            let (ax, ay): (T, T) = (x.abs(), y.abs());
+            if y == T::ZERO && ax <= T::ONE {
+                return Self::new(x.atanh(), y);
+            }
+            if x == T::ZERO {
+                return Self::new(x, y.atan2(T::ONE));
+            }
+            if ax > T::ONE / T::EPSILON || ay > T::ONE / T::EPSILON {
+                return Self::new(self.recip().real, T::FRAC_PI_2.copysign(y));
+            }
+            let tiny: T = Self::sqrt_eps_times(3) * T::HALF;
+            if ax < tiny && ay < tiny {
+                return self;
+            }
+            let four: T = T::from_i32(4);
+            let rx: T = if ax == T::ONE && ay < T::EPSILON {
+                (T::LN_2 - ay.ln()) * T::HALF
+            } else {
+                let d: T = ax - T::ONE;
+                (four * ax / (d * d + ay * ay)).ln_1p() / four
+            };
+            let ry: T = if ax == T::ONE {
+                T::TWO.atan2(-ay) * T::HALF
+            } else if ay < T::EPSILON {
+                (T::TWO * ay).atan2((T::ONE - ax) * (T::ONE + ax)) * T::HALF
+            } else {
+                (T::TWO * ay).atan2((T::ONE - ax) * (T::ONE + ax) - ay * ay) * T::HALF
+            };
+            Self::new(rx.copysign(x), ry.copysign(y))
+        }
+        #[inline(always)]
+        fn sqrt_eps_times(k: i32) -> T {
+            (T::from_i32(k) * T::EPSILON).sqrt()
+        }
+        #[inline]
+        fn ln_hypot(ax: T, ay: T) -> T {
+            if ax.max(ay) > T::MAX * T::HALF {
+                (ax * T::HALF).hypot(ay * T::HALF).ln() + T::LN_2
+            } else {
+                ax.hypot(ay).ln()
+            }
+        }
+        #[inline(always)]
+        fn hft_f(a: T, b: T, hypot_a_b: T) -> T {
+            if b < T::ZERO {
+                (hypot_a_b - b) * T::HALF
+            } else if b == T::ZERO {
+                a * T::HALF
+            } else {
+                a * a / (hypot_a_b + b) * T::HALF
+            }
+        }
+        // Hull, Fairgrieve and Tang (1997), as arranged in FreeBSD's catrig.c, for x, y >= 0:
+        // returns Re asinh(x + iy), Im asinh as `asin(b)` when `b` is usable,
+        // otherwise as `atan2(new_y, sqrt_a2my2)`.
+        fn hull_fairgrieve_tang(x: T, y: T) -> (T, Option<T>, T, T) {
+            let eps: T = T::EPSILON;
+            let r: T = x.hypot(y + T::ONE);
+            let s: T = x.hypot(y - T::ONE);
+            let a: T = ((r + s) * T::HALF).max(T::ONE);
+            let rx: T = if a < T::from_i32(10) {
+                if y == T::ONE && x < eps * eps / T::from_i32(128) {
+                    x.sqrt()
+                } else if x >= eps * (y - T::ONE).abs() {
+                    let am1: T = Self::hft_f(x, T::ONE + y, r) + Self::hft_f(x, T::ONE - y, s);
+                    (am1 + (am1 * (a + T::ONE)).sqrt()).ln_1p()
+                } else if y < T::ONE {
+                    x / ((T::ONE - y) * (T::ONE + y)).sqrt()
+                } else {
+                    ((y - T::ONE) + ((y - T::ONE) * (y + T::ONE)).sqrt()).ln_1p()
+                }
+            } else {
+                (a + (a * a - T::ONE).sqrt()).ln()
+            };
+            if y < T::from_i32(4) * T::MIN_POSITIVE.sqrt() {
+                let scale: T = T::TWO / eps;
+                return (rx, None, a * scale, y * scale);
+            }
+            let b: T = y / a;
+            if b <= T::from_i32(6417) / T::from_i32(10000) {
+                return (rx, Some(b), T::ZERO, y);
+            }
+            if y == T::ONE && x < eps / T::from_i32(128) {
+                (rx, None, x.sqrt() * ((a + y) * T::HALF).sqrt(), y)
+            } else if x >= eps * (y - T::ONE).abs() {
+                let amy: T = Self::hft_f(x, y + T::ONE, r) + Self::hft_f(x, y - T::ONE, s);
+                (rx, None, (amy * (a + y)).sqrt(), y)
+            } else if y > T::ONE {
+                let scale: T = T::from_i32(4) / eps / eps;
+                (rx, None, x * scale * y / ((y + T::ONE) * (y - T::ONE)).sqrt(), y * scale)
+            } else {
+                (rx, None, ((T::ONE - y) * (T::ONE + y)).sqrt(), y)
+            }
+        }
+        #[inline(always)]
+        fn div_smith_real(a: T, b: T, c: T, d: T, r: T, t: T) -> T {
+            if r != T::ZERO {
+                let br: T = b * r;
+                if br != T::ZERO {
+                    (a + br) * t
+                } else {
+                    a * t + (b * t) * r
+                }
+            } else {
+                (a + d * (b / c)) * t
+            }
+        }
+        #[inline(always)]
+        fn div_smith(a: T, b: T, c: T, d: T) -> (T, T) {
+            let r: T = d / c;
+            let t: T = T::ONE / (c + d * r);
+            (
+                Self::div_smith_real(a, b, c, d, r, t),
+                Self::div_smith_real(b, -a, c, d, r, t),
+            )
+        }
+        #[inline]
+        fn div_robust(self, other: Self) -> Self {
+            let (mut a, mut b, mut c, mut d): (T, T, T, T) = (self.real, self.imag, other.real, other.imag);
+            let eps: T = T::EPSILON * T::HALF;
+            let small: T = T::MIN_POSITIVE * T::TWO / eps;
+            let big: T = T::TWO / (eps * eps);
+            let mut scale: T = T::ONE;
+            if a.abs().max(b.abs()) >= T::MAX * T::HALF {
+                (a, b) = (a * T::HALF, b * T::HALF);
+                scale *= T::TWO;
+            }
+            if c.abs().max(d.abs()) >= T::MAX * T::HALF {
+                (c, d) = (c * T::HALF, d * T::HALF);
+                scale *= T::HALF;
+            }
+            if a.abs().max(b.abs()) <= small {
+                (a, b) = (a * big, b * big);
+                scale /= big;
+            }
+            if c.abs().max(d.abs()) <= small {
+                (c, d) = (c * big, d * big);
+                scale *= big;
+            }
+            let (mut real, mut imag): (T, T) = if d.abs() <= c.abs() {
+                Self::div_smith(a, b, c, d)
+            } else {
+                let (real, imag): (T, T) = Self::div_smith(b, a, d, c);
+                (real, -imag)
+            };
+            real *= scale;
+            imag *= scale;
+            if real.is_nan() && imag.is_nan() {
+                let (a, b, c, d): (T, T, T, T) = (self.real, self.imag, other.real, other.imag);
+                let unit = |x: T| -> T { if x.is_infinite() { T::ONE } else { T::ZERO }.copysign(x) };
+                if c == T::ZERO && d == T::ZERO && (!a.is_nan() || !b.is_nan()) {
+                    real = T::INFINITY.copysign(c) * a;
+                    imag = T::INFINITY.copysign(c) * b;
+                } else if (a.is_infinite() || b.is_infinite()) && c.is_finite() && d.is_finite() {
+                    let (a, b): (T, T) = (unit(a), unit(b));
+                    real = T::INFINITY * (a * c + b * d);
+                    imag = T::INFINITY * (b * c - a * d);
+                } else if (c.is_infinite() || d.is_infinite()) && a.is_finite() && b.is_finite() {
+                    let (c, d): (T, T) = (unit(c), unit(d));
+                    real = T::ZERO * (a * c + b * d);
+                    imag = T::ZERO * (b * c - a * d);
+                }
+            }
+            Self::new(real, imag)
         }
     }
-    impl Neg for Complex {
+    impl<T: Scalar> Neg for ComplexT<T> {
         type Output = Self;
         #[inline(always)]
         fn neg(self) -> Self::Output {
@@ -170,7 +1030,7 @@ mod complex {
             )
         }
     }
-    impl Add for Complex {
+    impl<T: Scalar> Add for ComplexT<T> {
         type Output = Self;
         #[inline(always)]
         fn add(self, other: Self) -> Self::Output {
@@ -180,30 +1040,30 @@ mod complex {
             )
         }
     }
-    impl AddAssign for Complex {
+    impl<T: Scalar> AddAssign for ComplexT<T> {
         #[inline(always)]
         fn add_assign(&mut self, other: Self) -> () {
             self.real += other.real;
             self.imag += other.imag;
         }
     }
-    impl Add<f64> for Complex {
+    impl<T: Scalar> Add<T> for ComplexT<T> {
         type Output = Self;
         #[inline(always)]
-        fn add(self, rhs: f64) -> Self::Output {
+        fn add(self, rhs: T) -> Self::Output {
             Self::new(
                 self.real + rhs,
                 self.imag,
             )
         }
     }
-    impl AddAssign<f64> for Complex {
+    impl<T: Scalar> AddAssign<T> for ComplexT<T> {
         #[inline(always)]
-        fn add_assign(&mut self, rhs: f64) -> () {
+        fn add_assign(&mut self, rhs: T) -> () {
             self.real += rhs;
         }
     }
-    impl Sub for Complex {
+    impl<T: Scalar> Sub for ComplexT<T> {
         type Output = Self;
         #[inline(always)]
         fn sub(self, other: Self) -> Self::Output {
@@ -213,104 +1073,655 @@ mod complex {
             )
         }
     }
-    impl SubAssign for Complex {
+    impl<T: Scalar> SubAssign for ComplexT<T> {
         #[inline(always)]
         fn sub_assign(&mut self, other: Self) -> () {
             self.real -= other.real;
             self.imag -= other.imag;
         }
     }
-    impl Sub<f64> for Complex {
+    impl<T: Scalar> Sub<T> for ComplexT<T> {
         type Output = Self;
         #[inline(always)]
-        fn sub(self, rhs: f64) -> Self::Output {
+        fn sub(self, rhs: T) -> Self::Output {
             Self::new(
                 self.real - rhs,
                 self.imag,
             )
         }
     }
-    impl SubAssign<f64> for Complex {
+    impl<T: Scalar> SubAssign<T> for ComplexT<T> {
         #[inline(always)]
-        fn sub_assign(&mut self, rhs: f64) -> () {
+        fn sub_assign(&mut self, rhs: T) -> () {
             self.real -= rhs;
         }
     }
-    impl Mul for Complex {
+    impl<T: Scalar> Mul for ComplexT<T> {
         type Output = Self;
         #[inline(always)]
         fn mul(self, other: Self) -> Self::Output {
             Self::new(
-                self.real * other.real - self.imag * other.imag,
-                self.real * other.imag + self.imag * other.real,
+                T::diff_of_products(self.real, other.real, self.imag, other.imag),
+                T::sum_of_products(self.imag, other.real, self.real, other.imag),
             )
         }
     }
-    impl MulAssign for Complex {
+    impl<T: Scalar> MulAssign for ComplexT<T> {
         #[inline(always)]
         fn mul_assign(&mut self, other: Self) -> () {
             (self.real, self.imag) = (
-                self.real * other.real - self.imag * other.imag,
-                self.real * other.imag + self.imag * other.real,
+                T::diff_of_products(self.real, other.real, self.imag, other.imag),
+                T::sum_of_products(self.imag, other.real, self.real, other.imag),
             );
         }
     }
-    impl Mul<f64> for Complex {
+    impl<T: Scalar> Mul<T> for ComplexT<T> {
         type Output = Self;
         #[inline(always)]
-        fn mul(self, rhs: f64) -> Self::Output {
+        fn mul(self, rhs: T) -> Self::Output {
             Self::new(
                 self.real * rhs,
                 self.imag * rhs,
             )
         }
     }
-    impl MulAssign<f64> for Complex {
+    impl<T: Scalar> MulAssign<T> for ComplexT<T> {
         #[inline(always)]
-        fn mul_assign(&mut self, rhs: f64) -> () {
+        fn mul_assign(&mut self, rhs: T) -> () {
             self.real *= rhs;
             self.imag *= rhs;
         }
     }
-    impl Div for Complex {
+    impl<T: Float> Div for ComplexT<T> {
         type Output = Self;
         #[inline(always)]
         fn div(self, other: Self) -> Self::Output {
-            let denom: f64 = other.norm();
-            Self::new(
-                (self.real * other.real + self.imag * other.imag) / denom,
-                (self.imag * other.real - self.real * other.imag) / denom,
-            )
+            self.div_robust(other)
         }
     }
-    impl DivAssign for Complex {
+    impl<T: Float> DivAssign for ComplexT<T> {
         #[inline(always)]
         fn div_assign(&mut self, other: Self) -> () {
-            let denom: f64 = other.norm();
-            (self.real, self.imag) = (
-                (self.real * other.real + self.imag * other.imag) / denom,
-                (self.imag * other.real - self.real * other.imag) / denom,
-            );
+            *self = self.div_robust(other);
         }
     }
-    impl Div<f64> for Complex {
+    impl<T: Float> Div<T> for ComplexT<T> {
         type Output = Self;
         #[inline(always)]
-        fn div(self, rhs: f64) -> Self::Output {
+        fn div(self, rhs: T) -> Self::Output {
             Self::new(
                 self.real / rhs,
                 self.imag / rhs,
             )
         }
     }
-    impl DivAssign<f64> for Complex {
+    impl<T: Float> DivAssign<T> for ComplexT<T> {
         #[inline(always)]
-        fn div_assign(&mut self, rhs: f64) -> () {
+        fn div_assign(&mut self, rhs: T) -> () {
             self.real /= rhs;
             self.imag /= rhs;
         }
     }
-    impl fmt::Display for Complex {
+    #[allow(dead_code)]
+    impl ComplexT<i64> {
+        // a * b + c * d as a sign and a magnitude: it reaches 2^127 when all four are i64::MIN
+        #[inline(always)]
+        fn dot_wide(ab: i128, cd: i128) -> (bool, u128) {
+            match ab.checked_add(cd) {
+                Some(sum) => (sum < 0, sum.unsigned_abs()),
+                None => (false, ab as u128 + cd as u128),
+            }
+        }
+        // rounds half up, like floor(num / denom + 1/2)
+        #[inline(always)]
+        fn div_round((neg, num): (bool, u128), denom: u128) -> i128 {
+            let (quot, rem): (u128, u128) = (num / denom, num % denom);
+            let quot: i128 = (quot + (if neg { rem > denom - rem } else { rem >= denom - rem }) as u128) as i128;
+            if neg { -quot } else { quot }
+        }
+        // Components of magnitude up to 2^63, so the quotient stays within about 2^64 and every
+        // product below within i128; the remainder has N(r) <= N(b) / 2.
+        fn div_rem_wide((a, b): (i128, i128), (c, d): (i128, i128)) -> ((i128, i128), (i128, i128)) {
+            let denom: u128 = c.unsigned_abs() * c.unsigned_abs() + d.unsigned_abs() * d.unsigned_abs();
+            let (qr, qi): (i128, i128) = (
+                Self::div_round(Self::dot_wide(a * c, b * d), denom),
+                Self::div_round(Self::dot_wide(b * c, -(a * d)), denom),
+            );
+            ((qr, qi), (a - (qr * c - qi * d), b - (qr * d + qi * c)))
+        }
+        // Exact in i128; panics if the quotient or the remainder does not fit in i64,
+        // e.g. for (i64::MIN, 0) / (0, 1).
+        pub fn div_rem(self, other: Self) -> (Self, Self) {
+            let narrow = |(re, im): (i128, i128)| -> Option<Self> { Some(Self::new(re.try_into().ok()?, im.try_into().ok()?)) };
+            let (quot, rem): ((i128, i128), (i128, i128)) = Self::div_rem_wide(self.widen(), other.widen());
+            (
+                narrow(quot).expect("GaussianInt::div_rem: quotient out of range"),
+                narrow(rem).expect("GaussianInt::div_rem: remainder out of range"),
+            )
+        }
+        #[inline(always)]
+        fn widen(self) -> (i128, i128) {
+            (self.real as i128, self.imag as i128)
+        }
+        // The exact norm, at most 2^127; `norm()` overflows once it passes i64::MAX,
+        // i.e. for |z| above about 3.04e9.
+        #[inline(always)]
+        pub fn norm_wide(self) -> u128 {
+            let (re, im): (u128, u128) = (self.real.unsigned_abs() as u128, self.imag.unsigned_abs() as u128);
+            re * re + im * im
+        }
+        #[inline(always)]
+        pub fn is_zero(self) -> bool {
+            self.real == 0 && self.imag == 0
+        }
+        #[inline(always)]
+        pub fn is_unit(self) -> bool {
+            self.norm_wide() == 1
+        }
+        // The associate with re > 0 and im >= 0. With a component at i64::MIN every such associate
+        // has a component of 2^63, so that panics instead of wrapping.
+        #[inline(always)]
+        pub fn normalize(self) -> Self {
+            let neg = |x: i64| -> i64 { x.checked_neg().expect("GaussianInt::normalize: component out of range") };
+            match (self.real, self.imag) {
+                (re, im) if re > 0 && im >= 0 => self,
+                (re, im) if re <= 0 && im > 0 => Self::new(im, neg(re)),
+                (re, im) if re < 0 && im <= 0 => Self::new(neg(re), neg(im)),
+                (re, im) => Self::new(neg(im), re),
+            }
+        }
+        // Euclid on i128 remainders, which always fit; panics only if the normalized gcd itself
+        // does not fit in i64, e.g. 2^63 for (i64::MIN, 0) with itself.
+        pub fn gcd(self, other: Self) -> Self {
+            let (mut a, mut b): ((i128, i128), (i128, i128)) = (self.widen(), other.widen());
+            while b != (0, 0) {
+                (a, b) = (b, Self::div_rem_wide(a, b).1);
+            }
+            let fits = |x: i128| -> bool { x.unsigned_abs() <= i64::MAX as u128 };
+            assert!(fits(a.0) && fits(a.1), "GaussianInt::gcd: result out of range");
+            let a: Self = Self::new(a.0 as i64, a.1 as i64);
+            if a.is_zero() { a } else { a.normalize() }
+        }
+        // Deterministic for norms below 3.3e24 (|z| below about 1.8e12); above that, a strong
+        // probable-prime test to the first 12 prime bases.
+        pub fn is_prime(self) -> bool {
+            if self.real == 0 || self.imag == 0 {
+                let n: u64 = self.real.unsigned_abs().max(self.imag.unsigned_abs());
+                n % 4 == 3 && Self::is_prime_wide(n as u128)
+            } else {
+                Self::is_prime_wide(self.norm_wide())
+            }
+        }
+        fn is_prime_wide(n: u128) -> bool {
+            const BASES: [u128; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+            if n < 2 {
+                return false;
+            }
+            for &p in BASES.iter() {
+                if n % p == 0 {
+                    return n == p;
+                }
+            }
+            let mul_mod = |a: u128, b: u128| -> u128 {
+                if n <= u64::MAX as u128 {
+                    return a * b % n;
+                }
+                // shift and add: n is odd and at most 2^127, so nothing below 2n overflows
+                let (mut a, mut b, mut acc): (u128, u128, u128) = (a, b, 0);
+                while b > 0 {
+                    if b & 1 == 1 {
+                        acc += a;
+                        if acc >= n {
+                            acc -= n;
+                        }
+                    }
+                    a <<= 1;
+                    if a >= n {
+                        a -= n;
+                    }
+                    b >>= 1;
+                }
+                acc
+            };
+            let pow_mod = |mut base: u128, mut exp: u128| -> u128 {
+                let mut acc: u128 = 1;
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        acc = mul_mod(acc, base);
+                    }
+                    base = mul_mod(base, base);
+                    exp >>= 1;
+                }
+                acc
+            };
+            let shift: u32 = (n - 1).trailing_zeros();
+            let odd: u128 = (n - 1) >> shift;
+            'witness: for &a in BASES.iter() {
+                let mut x: u128 = pow_mod(a, odd);
+                if x == 1 || x == n - 1 {
+                    continue;
+                }
+                for _ in 1..shift {
+                    x = mul_mod(x, x);
+                    if x == n - 1 {
+                        continue 'witness;
+                    }
+                }
+                return false;
+            }
+            true
+        }
+    }
+    macro_rules! impl_scalar_lhs {
+        ($t:ident) => {
+            impl Add<ComplexT<$t>> for $t {
+                type Output = ComplexT<$t>;
+                #[inline(always)]
+                fn add(self, rhs: ComplexT<$t>) -> Self::Output {
+                    ComplexT::new(
+                        self + rhs.real,
+                        rhs.imag,
+                    )
+                }
+            }
+            impl Sub<ComplexT<$t>> for $t {
+                type Output = ComplexT<$t>;
+                #[inline(always)]
+                fn sub(self, rhs: ComplexT<$t>) -> Self::Output {
+                    ComplexT::new(
+                        self - rhs.real,
+                        -rhs.imag,
+                    )
+                }
+            }
+            impl Mul<ComplexT<$t>> for $t {
+                type Output = ComplexT<$t>;
+                #[inline(always)]
+                fn mul(self, rhs: ComplexT<$t>) -> Self::Output {
+                    ComplexT::new(
+                        self * rhs.real,
+                        self * rhs.imag,
+                    )
+                }
+            }
+            impl Div<ComplexT<$t>> for $t {
+                type Output = ComplexT<$t>;
+                #[inline(always)]
+                fn div(self, rhs: ComplexT<$t>) -> Self::Output {
+                    ComplexT::with_real(self) / rhs
+                }
+            }
+        };
+    }
+    impl_scalar_lhs!(f32);
+    impl_scalar_lhs!(f64);
+    impl_scalar_lhs!(i64);
+    macro_rules! impl_int_lhs {
+        ($i:ident, $t:ident) => {
+            impl Add<ComplexT<$t>> for $i {
+                type Output = ComplexT<$t>;
+                #[inline(always)]
+                fn add(self, rhs: ComplexT<$t>) -> Self::Output {
+                    self as $t + rhs
+                }
+            }
+            impl Sub<ComplexT<$t>> for $i {
+                type Output = ComplexT<$t>;
+                #[inline(always)]
+                fn sub(self, rhs: ComplexT<$t>) -> Self::Output {
+                    self as $t - rhs
+                }
+            }
+            impl Mul<ComplexT<$t>> for $i {
+                type Output = ComplexT<$t>;
+                #[inline(always)]
+                fn mul(self, rhs: ComplexT<$t>) -> Self::Output {
+                    self as $t * rhs
+                }
+            }
+            impl Div<ComplexT<$t>> for $i {
+                type Output = ComplexT<$t>;
+                #[inline(always)]
+                fn div(self, rhs: ComplexT<$t>) -> Self::Output {
+                    self as $t / rhs
+                }
+            }
+        };
+    }
+    impl_int_lhs!(i32, f32);
+    impl_int_lhs!(i32, f64);
+    impl Div for ComplexT<i64> {
+        type Output = Self;
+        #[inline(always)]
+        fn div(self, other: Self) -> Self::Output {
+            self.div_rem(other).0
+        }
+    }
+    impl DivAssign for ComplexT<i64> {
+        #[inline(always)]
+        fn div_assign(&mut self, other: Self) -> () {
+            *self = self.div_rem(other).0;
+        }
+    }
+    impl Rem for ComplexT<i64> {
+        type Output = Self;
+        #[inline(always)]
+        fn rem(self, other: Self) -> Self::Output {
+            self.div_rem(other).1
+        }
+    }
+    impl RemAssign for ComplexT<i64> {
+        #[inline(always)]
+        fn rem_assign(&mut self, other: Self) -> () {
+            *self = self.div_rem(other).1;
+        }
+    }
+    // Truncates each component of `self / other` toward zero, like `%` on floats, so `(self % other) / other`
+    // keeps the signs of the quotient; the `GaussianInt` `%` rounds to nearest instead.
+    impl<T: Float> Rem for ComplexT<T> {
+        type Output = Self;
+        #[inline(always)]
+        fn rem(self, other: Self) -> Self::Output {
+            let q: Self = self / other;
+            let n: Self = Self::new(q.real - q.real % T::ONE, q.imag - q.imag % T::ONE);
+            self - other * n
+        }
+    }
+    impl<T: Float> RemAssign for ComplexT<T> {
+        #[inline(always)]
+        fn rem_assign(&mut self, other: Self) -> () {
+            *self = *self % other;
+        }
+    }
+    macro_rules! forward_ref_binop {
+        (impl<$($g:ident: $bound:ident)?> $imp:ident, $method:ident for $t:ty, $rhs:ty) => {
+            impl<'a, $($g: $bound)?> $imp<$rhs> for &'a $t {
+                type Output = $t;
+                #[inline(always)]
+                fn $method(self, other: $rhs) -> Self::Output {
+                    (*self).$method(other)
+                }
+            }
+            impl<'a, $($g: $bound)?> $imp<&'a $rhs> for $t {
+                type Output = $t;
+                #[inline(always)]
+                fn $method(self, other: &'a $rhs) -> Self::Output {
+                    self.$method(*other)
+                }
+            }
+            impl<'a, 'b, $($g: $bound)?> $imp<&'a $rhs> for &'b $t {
+                type Output = $t;
+                #[inline(always)]
+                fn $method(self, other: &'a $rhs) -> Self::Output {
+                    (*self).$method(*other)
+                }
+            }
+        };
+    }
+    macro_rules! forward_ref_op_assign {
+        (impl<$($g:ident: $bound:ident)?> $imp:ident, $method:ident for $t:ty, $rhs:ty) => {
+            impl<'a, $($g: $bound)?> $imp<&'a $rhs> for $t {
+                #[inline(always)]
+                fn $method(&mut self, other: &'a $rhs) -> () {
+                    self.$method(*other);
+                }
+            }
+        };
+    }
+    forward_ref_binop!(impl<T: Scalar> Add, add for ComplexT<T>, ComplexT<T>);
+    forward_ref_binop!(impl<T: Scalar> Add, add for ComplexT<T>, T);
+    forward_ref_binop!(impl<T: Scalar> Sub, sub for ComplexT<T>, ComplexT<T>);
+    forward_ref_binop!(impl<T: Scalar> Sub, sub for ComplexT<T>, T);
+    forward_ref_binop!(impl<T: Scalar> Mul, mul for ComplexT<T>, ComplexT<T>);
+    forward_ref_binop!(impl<T: Scalar> Mul, mul for ComplexT<T>, T);
+    forward_ref_binop!(impl<T: Float> Div, div for ComplexT<T>, ComplexT<T>);
+    forward_ref_binop!(impl<T: Float> Div, div for ComplexT<T>, T);
+    forward_ref_binop!(impl<> Div, div for ComplexT<i64>, ComplexT<i64>);
+    forward_ref_binop!(impl<T: Float> Rem, rem for ComplexT<T>, ComplexT<T>);
+    forward_ref_binop!(impl<> Rem, rem for ComplexT<i64>, ComplexT<i64>);
+    forward_ref_op_assign!(impl<T: Scalar> AddAssign, add_assign for ComplexT<T>, ComplexT<T>);
+    forward_ref_op_assign!(impl<T: Scalar> AddAssign, add_assign for ComplexT<T>, T);
+    forward_ref_op_assign!(impl<T: Scalar> SubAssign, sub_assign for ComplexT<T>, ComplexT<T>);
+    forward_ref_op_assign!(impl<T: Scalar> SubAssign, sub_assign for ComplexT<T>, T);
+    forward_ref_op_assign!(impl<T: Scalar> MulAssign, mul_assign for ComplexT<T>, ComplexT<T>);
+    forward_ref_op_assign!(impl<T: Scalar> MulAssign, mul_assign for ComplexT<T>, T);
+    forward_ref_op_assign!(impl<T: Float> DivAssign, div_assign for ComplexT<T>, ComplexT<T>);
+    forward_ref_op_assign!(impl<T: Float> DivAssign, div_assign for ComplexT<T>, T);
+    forward_ref_op_assign!(impl<> DivAssign, div_assign for ComplexT<i64>, ComplexT<i64>);
+    forward_ref_op_assign!(impl<T: Float> RemAssign, rem_assign for ComplexT<T>, ComplexT<T>);
+    forward_ref_op_assign!(impl<> RemAssign, rem_assign for ComplexT<i64>, ComplexT<i64>);
+    impl<T: Scalar> Neg for &ComplexT<T> {
+        type Output = ComplexT<T>;
+        #[inline(always)]
+        fn neg(self) -> Self::Output {
+            -*self
+        }
+    }
+    impl<T: Scalar> Sum for ComplexT<T> {
+        #[inline(always)]
+        fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+            iter.fold(Self::new(T::ZERO, T::ZERO), |acc, z| acc + z)
+        }
+    }
+    impl<'a, T: Scalar> Sum<&'a ComplexT<T>> for ComplexT<T> {
+        #[inline(always)]
+        fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+            iter.fold(Self::new(T::ZERO, T::ZERO), |acc, z| acc + *z)
+        }
+    }
+    impl<T: Scalar> Product for ComplexT<T> {
+        #[inline(always)]
+        fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+            iter.fold(Self::REAL_UNIT, |acc, z| acc * z)
+        }
+    }
+    impl<'a, T: Scalar> Product<&'a ComplexT<T>> for ComplexT<T> {
+        #[inline(always)]
+        fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+            iter.fold(Self::REAL_UNIT, |acc, z| acc * *z)
+        }
+    }
+    #[derive(Copy, Clone, Default)]
+    pub struct CompensatedSum<T = f64> {
+        sum: ComplexT<T>,
+        comp: ComplexT<T>,
+    }
+    #[allow(dead_code)]
+    impl<T: Float> CompensatedSum<T> {
+        #[inline(always)]
+        pub fn new() -> Self {
+            Self {
+                sum: ComplexT::new(T::ZERO, T::ZERO),
+                comp: ComplexT::new(T::ZERO, T::ZERO),
+            }
+        }
+        #[inline(always)]
+        fn step(sum: &mut T, comp: &mut T, x: T) -> () {
+            let t: T = *sum + x;
+            if sum.abs() >= x.abs() {
+                *comp += (*sum - t) + x;
+            } else {
+                *comp += (x - t) + *sum;
+            }
+            *sum = t;
+        }
+        #[inline(always)]
+        pub fn add(&mut self, z: ComplexT<T>) -> () {
+            Self::step(&mut self.sum.real, &mut self.comp.real, z.real);
+            Self::step(&mut self.sum.imag, &mut self.comp.imag, z.imag);
+        }
+        #[inline(always)]
+        pub fn total(&self) -> ComplexT<T> {
+            self.sum + self.comp
+        }
+    }
+    impl<T: Float> AddAssign<ComplexT<T>> for CompensatedSum<T> {
+        #[inline(always)]
+        fn add_assign(&mut self, z: ComplexT<T>) -> () {
+            self.add(z);
+        }
+    }
+    #[allow(dead_code)]
+    impl<T: Float> ComplexT<T> {
+        #[inline(always)]
+        pub fn sum_compensated<I>(iter: I) -> Self
+        where
+            I: IntoIterator,
+            I::Item: Borrow<Self>,
+        {
+            let mut acc: CompensatedSum<T> = CompensatedSum::new();
+            for z in iter {
+                acc.add(*z.borrow());
+            }
+            acc.total()
+        }
+    }
+    #[allow(dead_code)]
+    #[derive(Copy, Clone, Default)]
+    pub struct TotalComplex<T = f64>(pub ComplexT<T>);
+    #[allow(dead_code)]
+    impl<T: Float> TotalComplex<T> {
+        #[inline(always)]
+        fn canonical(x: T) -> T {
+            if x.is_nan() { T::NAN } else { x }
+        }
+    }
+    impl<T: Float> From<ComplexT<T>> for TotalComplex<T> {
+        #[inline(always)]
+        fn from(z: ComplexT<T>) -> Self {
+            Self(z)
+        }
+    }
+    impl<T: Float> PartialEq for TotalComplex<T> {
+        #[inline(always)]
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+    impl<T: Float> Eq for TotalComplex<T> {}
+    impl<T: Float> PartialOrd for TotalComplex<T> {
+        #[inline(always)]
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl<T: Float> Ord for TotalComplex<T> {
+        #[inline(always)]
+        fn cmp(&self, other: &Self) -> Ordering {
+            Self::canonical(self.0.real).total_cmp(&Self::canonical(other.0.real))
+                .then_with(|| Self::canonical(self.0.imag).total_cmp(&Self::canonical(other.0.imag)))
+        }
+    }
+    impl<T: Float> Hash for TotalComplex<T> {
+        #[inline(always)]
+        fn hash<H: Hasher>(&self, state: &mut H) -> () {
+            Self::canonical(self.0.real).to_bits().hash(state);
+            Self::canonical(self.0.imag).to_bits().hash(state);
+        }
+    }
+    impl<T: Float> fmt::Debug for TotalComplex<T> {
+        #[inline(always)]
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    #[derive(Copy, Clone, Default, PartialEq, Debug)]
+    pub struct Polar<T = f64> {
+        pub r: T,
+        pub theta: T,
+    }
+    #[allow(dead_code)]
+    impl<T: Float> Polar<T> {
+        #[inline(always)]
+        pub fn new(r: T, theta: T) -> Self {
+            Self { r, theta }
+        }
+        #[inline(always)]
+        pub fn to_complex(self) -> ComplexT<T> {
+            ComplexT::from_polar(self.r, self.theta)
+        }
+        #[inline(always)]
+        pub fn normalize_angle(theta: T) -> T {
+            let two_pi: T = T::PI * T::TWO;
+            let theta: T = theta % two_pi;
+            if theta > T::PI {
+                theta - two_pi
+            } else if theta <= -T::PI {
+                theta + two_pi
+            } else {
+                theta
+            }
+        }
+        #[inline(always)]
+        pub fn normalize(self) -> Self {
+            if self.r < T::ZERO {
+                Self::new(-self.r, Self::normalize_angle(self.theta + T::PI))
+            } else {
+                Self::new(self.r, Self::normalize_angle(self.theta))
+            }
+        }
+        #[inline(always)]
+        pub fn conj(self) -> Self {
+            Self::new(self.r, -self.theta)
+        }
+        #[inline(always)]
+        pub fn recip(self) -> Self {
+            Self::new(T::ONE / self.r, -self.theta).normalize()
+        }
+        #[inline(always)]
+        pub fn sqrt(self) -> Self {
+            let polar: Self = self.normalize();
+            Self::new(polar.r.sqrt(), polar.theta * T::HALF)
+        }
+        #[inline(always)]
+        pub fn powi(self, exp: i32) -> Self {
+            Self::new(self.r.powi(exp), self.theta * T::from_i32(exp)).normalize()
+        }
+        #[inline(always)]
+        pub fn powf(self, exp: T) -> Self {
+            let polar: Self = self.normalize();
+            Self::new(polar.r.powf(exp), polar.theta * exp).normalize()
+        }
+    }
+    impl<T: Float> From<ComplexT<T>> for Polar<T> {
+        #[inline(always)]
+        fn from(z: ComplexT<T>) -> Self {
+            z.to_polar()
+        }
+    }
+    impl<T: Float> From<Polar<T>> for ComplexT<T> {
+        #[inline(always)]
+        fn from(p: Polar<T>) -> Self {
+            p.to_complex()
+        }
+    }
+    impl<T: Float> Mul for Polar<T> {
+        type Output = Self;
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self::Output {
+            Self::new(self.r * other.r, self.theta + other.theta).normalize()
+        }
+    }
+    impl<T: Float> MulAssign for Polar<T> {
+        #[inline(always)]
+        fn mul_assign(&mut self, other: Self) -> () {
+            *self = *self * other;
+        }
+    }
+    impl<T: Float> Div for Polar<T> {
+        type Output = Self;
+        #[inline(always)]
+        fn div(self, other: Self) -> Self::Output {
+            Self::new(self.r / other.r, self.theta - other.theta).normalize()
+        }
+    }
+    impl<T: Float> DivAssign for Polar<T> {
+        #[inline(always)]
+        fn div_assign(&mut self, other: Self) -> () {
+            *self = *self / other;
+        }
+    }
+    impl<T: Scalar> fmt::Display for ComplexT<T> {
         #[inline(always)]
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             if let Some(precision) = f.precision() {
@@ -320,11 +1731,90 @@ mod complex {
             }
         }
     }
-    impl fmt::Debug for Complex {
+    impl<T: Scalar> fmt::Debug for ComplexT<T> {
         #[inline(always)]
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             write!(f, "{}{:+}i", self.real, self.imag)
         }
     }
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ParseComplexError {
+        Empty,
+        InvalidReal,
+        InvalidImag,
+        InvalidPair,
+    }
+    impl fmt::Display for ParseComplexError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(match self {
+                Self::Empty => "cannot parse complex number from empty string",
+                Self::InvalidReal => "invalid real part in complex number",
+                Self::InvalidImag => "invalid imaginary part in complex number",
+                Self::InvalidPair => "invalid (real, imag) pair in complex number",
+            })
+        }
+    }
+    impl core::error::Error for ParseComplexError {}
+    #[allow(dead_code)]
+    impl<T: Scalar> ComplexT<T> {
+        fn parse_real(s: &str, parse: &impl Fn(&str) -> Option<T>) -> Result<T, ParseComplexError> {
+            parse(s.trim()).ok_or(ParseComplexError::InvalidReal)
+        }
+        fn parse_imag(s: &str, parse: &impl Fn(&str) -> Option<T>) -> Result<T, ParseComplexError> {
+            let s: &str = s.trim();
+            let (neg, rest): (bool, &str) = match s.as_bytes().first() {
+                Some(b'+') => (false, s[1..].trim_start()),
+                Some(b'-') => (true, s[1..].trim_start()),
+                _ => (false, s),
+            };
+            let value: T = if rest.is_empty() {
+                T::ONE
+            } else {
+                parse(rest).ok_or(ParseComplexError::InvalidImag)?
+            };
+            Ok(if neg { -value } else { value })
+        }
+        fn split_imag(body: &str) -> Option<usize> {
+            let bytes: &[u8] = body.as_bytes();
+            let mut split: Option<usize> = None;
+            for i in 1..bytes.len() {
+                let prev: u8 = bytes[i - 1];
+                match bytes[i] {
+                    b'+' | b'-' if prev != b'e' && prev != b'E' => split = Some(i),
+                    b'n' | b'N' if prev != b'+' && prev != b'-'
+                        && body[i..].get(..3).is_some_and(|t| t.eq_ignore_ascii_case("nan")) => split = Some(i),
+                    _ => {}
+                }
+            }
+            split
+        }
+        pub(crate) fn parse_with(s: &str, parse: impl Fn(&str) -> Option<T>) -> Result<Self, ParseComplexError> {
+            let s: &str = s.trim();
+            if s.is_empty() {
+                return Err(ParseComplexError::Empty);
+            }
+            if let Some(inner) = s.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+                let (real, imag): (&str, &str) = inner.split_once(',').ok_or(ParseComplexError::InvalidPair)?;
+                return match (parse(real.trim()), parse(imag.trim())) {
+                    (Some(real), Some(imag)) => Ok(Self::new(real, imag)),
+                    _ => Err(ParseComplexError::InvalidPair),
+                };
+            }
+            match s.strip_suffix(['i', 'j']) {
+                Some(body) => match Self::split_imag(body) {
+                    Some(at) => Ok(Self::new(Self::parse_real(&body[..at], &parse)?, Self::parse_imag(&body[at..], &parse)?)),
+                    None => Ok(Self::new(T::ZERO, Self::parse_imag(body, &parse)?)),
+                },
+                None => Ok(Self::new(Self::parse_real(s, &parse)?, T::ZERO)),
+            }
+        }
+    }
+    impl<T: Scalar + FromStr> FromStr for ComplexT<T> {
+        type Err = ParseComplexError;
+        #[inline(always)]
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::parse_with(s, |t| t.parse::<T>().ok())
+        }
+    }
 }
 use complex::Complex;
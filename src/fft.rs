@@ -0,0 +1,222 @@
+//! In-place FFTs over `ComplexT` for any length.
+//!
+//! Lengths whose prime factors are all at most `MAX_RADIX` use a mixed-radix Cooley–Tukey
+//! decomposition (radix-4 and radix-2 butterflies, plus a generic butterfly for odd primes);
+//! every other length goes through Bluestein's chirp-z algorithm on a power-of-two plan.
+//! `forward` computes `X[k] = sum x[j] e^(-2 pi i jk/n)`; `inverse` uses `e^(+2 pi i jk/n)`
+//! and divides by `n`, so `inverse(forward(x)) == x` up to rounding.
+
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::complex::{ComplexT, Float};
+
+const MAX_RADIX: usize = 13;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Forward,
+    Inverse,
+}
+
+#[derive(Clone)]
+pub struct FftPlan<T = f64> {
+    len: usize,
+    algorithm: Algorithm<T>,
+}
+#[derive(Clone)]
+enum Algorithm<T> {
+    MixedRadix { factors: Vec<(usize, usize)>, twiddles: Vec<ComplexT<T>> },
+    Bluestein { inner: Box<FftPlan<T>>, chirp: Vec<ComplexT<T>>, kernel: Vec<ComplexT<T>> },
+}
+impl<T: Float> FftPlan<T> {
+    pub fn new(len: usize) -> Self {
+        assert!(len <= (i32::MAX / 4) as usize, "FFT length {} is too large", len);
+        let algorithm: Algorithm<T> = match Self::factorize(len) {
+            Some(factors) => Algorithm::MixedRadix { factors, twiddles: (0..len).map(|k| Self::twiddle(k, len)).collect() },
+            None => Self::bluestein(len),
+        };
+        Self { len, algorithm }
+    }
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn scratch_len(&self) -> usize {
+        match &self.algorithm {
+            Algorithm::MixedRadix { .. } => self.len,
+            Algorithm::Bluestein { inner, .. } => 2 * inner.len,
+        }
+    }
+    #[inline(always)]
+    pub fn forward(&self, buffer: &mut [ComplexT<T>]) -> () {
+        self.process(buffer, Direction::Forward)
+    }
+    #[inline(always)]
+    pub fn inverse(&self, buffer: &mut [ComplexT<T>]) -> () {
+        self.process(buffer, Direction::Inverse)
+    }
+    pub fn process(&self, buffer: &mut [ComplexT<T>], direction: Direction) -> () {
+        let mut scratch: Vec<ComplexT<T>> = vec![ComplexT::default(); self.scratch_len()];
+        self.process_with_scratch(buffer, &mut scratch, direction)
+    }
+    pub fn process_with_scratch(&self, buffer: &mut [ComplexT<T>], scratch: &mut [ComplexT<T>], direction: Direction) -> () {
+        assert_eq!(buffer.len(), self.len, "buffer length does not match the FFT plan");
+        assert!(scratch.len() >= self.scratch_len(), "FFT scratch buffer is too short");
+        if self.len <= 1 {
+            return;
+        }
+        if direction == Direction::Inverse {
+            for z in buffer.iter_mut() {
+                *z = z.conj();
+            }
+        }
+        match &self.algorithm {
+            Algorithm::MixedRadix { factors, twiddles } => {
+                let scratch: &mut [ComplexT<T>] = &mut scratch[..self.len];
+                scratch.copy_from_slice(buffer);
+                Self::mixed_radix(buffer, scratch, 1, factors, twiddles);
+            }
+            Algorithm::Bluestein { inner, chirp, kernel } => {
+                let (a, inner_scratch): (&mut [ComplexT<T>], &mut [ComplexT<T>]) = scratch.split_at_mut(inner.len);
+                for (i, slot) in a.iter_mut().enumerate() {
+                    *slot = if i < self.len { buffer[i] * chirp[i] } else { ComplexT::default() };
+                }
+                inner.process_with_scratch(a, inner_scratch, Direction::Forward);
+                for (x, &k) in a.iter_mut().zip(kernel.iter()) {
+                    *x *= k;
+                }
+                inner.process_with_scratch(a, inner_scratch, Direction::Inverse);
+                for ((z, &x), &w) in buffer.iter_mut().zip(a.iter()).zip(chirp.iter()) {
+                    *z = x * w;
+                }
+            }
+        }
+        if direction == Direction::Inverse {
+            let scale: T = T::ONE / T::from_i32(self.len as i32);
+            for z in buffer.iter_mut() {
+                *z = z.conj() * scale;
+            }
+        }
+    }
+    fn twiddle(k: usize, n: usize) -> ComplexT<T> {
+        let theta: T = -T::TWO * T::PI * T::from_i32(k as i32) / T::from_i32(n as i32);
+        ComplexT::from_polar(T::ONE, theta)
+    }
+    fn factorize(mut n: usize) -> Option<Vec<(usize, usize)>> {
+        let mut factors: Vec<(usize, usize)> = Vec::new();
+        let mut p: usize = 4;
+        while n > 1 {
+            while n % p != 0 {
+                p = match p {
+                    4 => 2,
+                    2 => 3,
+                    _ => p + 2,
+                };
+                if p > MAX_RADIX {
+                    return None;
+                }
+            }
+            n /= p;
+            factors.push((p, n));
+        }
+        Some(factors)
+    }
+    fn bluestein(len: usize) -> Algorithm<T> {
+        let m: usize = (2 * len - 1).next_power_of_two();
+        let inner: FftPlan<T> = FftPlan::new(m);
+        let chirp: Vec<ComplexT<T>> = (0..len)
+            .map(|k| {
+                let k2: u64 = (k as u64 * k as u64) % (2 * len as u64);
+                let theta: T = -T::PI * T::from_i32(k2 as i32) / T::from_i32(len as i32);
+                ComplexT::from_polar(T::ONE, theta)
+            })
+            .collect();
+        let mut kernel: Vec<ComplexT<T>> = vec![ComplexT::default(); m];
+        for (k, w) in chirp.iter().enumerate() {
+            kernel[k] = w.conj();
+            if k > 0 {
+                kernel[m - k] = w.conj();
+            }
+        }
+        inner.forward(&mut kernel);
+        Algorithm::Bluestein { inner: Box::new(inner), chirp, kernel }
+    }
+    fn mixed_radix(
+        out: &mut [ComplexT<T>],
+        input: &[ComplexT<T>],
+        stride: usize,
+        factors: &[(usize, usize)],
+        twiddles: &[ComplexT<T>],
+    ) -> () {
+        let (p, m): (usize, usize) = factors[0];
+        if m == 1 {
+            for (j, z) in out.iter_mut().enumerate() {
+                *z = input[j * stride];
+            }
+        } else {
+            for (q, chunk) in out.chunks_exact_mut(m).enumerate() {
+                Self::mixed_radix(chunk, &input[q * stride..], stride * p, &factors[1..], twiddles);
+            }
+        }
+        match p {
+            2 => Self::butterfly2(out, stride, m, twiddles),
+            4 => Self::butterfly4(out, stride, m, twiddles),
+            _ => Self::butterfly(out, stride, p, m, twiddles),
+        }
+    }
+    fn butterfly2(out: &mut [ComplexT<T>], stride: usize, m: usize, twiddles: &[ComplexT<T>]) -> () {
+        let (lo, hi): (&mut [ComplexT<T>], &mut [ComplexT<T>]) = out.split_at_mut(m);
+        for (k, (a, b)) in lo.iter_mut().zip(hi.iter_mut()).enumerate() {
+            let t: ComplexT<T> = *b * twiddles[k * stride];
+            *b = *a - t;
+            *a += t;
+        }
+    }
+    fn butterfly4(out: &mut [ComplexT<T>], stride: usize, m: usize, twiddles: &[ComplexT<T>]) -> () {
+        for k in 0..m {
+            let s0: ComplexT<T> = out[k + m] * twiddles[k * stride];
+            let s1: ComplexT<T> = out[k + 2 * m] * twiddles[2 * k * stride];
+            let s2: ComplexT<T> = out[k + 3 * m] * twiddles[3 * k * stride];
+            let s5: ComplexT<T> = out[k] - s1;
+            let s6: ComplexT<T> = out[k] + s1;
+            let s3: ComplexT<T> = s0 + s2;
+            let s4: ComplexT<T> = (s0 - s2).mul_neg_i();
+            out[k] = s6 + s3;
+            out[k + m] = s5 + s4;
+            out[k + 2 * m] = s6 - s3;
+            out[k + 3 * m] = s5 - s4;
+        }
+    }
+    fn butterfly(out: &mut [ComplexT<T>], stride: usize, p: usize, m: usize, twiddles: &[ComplexT<T>]) -> () {
+        let n: usize = twiddles.len();
+        let mut column: [ComplexT<T>; MAX_RADIX] = [ComplexT::default(); MAX_RADIX];
+        for u in 0..m {
+            for (q, slot) in column[..p].iter_mut().enumerate() {
+                *slot = out[u + q * m];
+            }
+            for q1 in 0..p {
+                let k: usize = u + q1 * m;
+                let mut acc: ComplexT<T> = column[0];
+                let mut index: usize = 0;
+                for &z in &column[1..p] {
+                    index = (index + stride * k) % n;
+                    acc += z * twiddles[index];
+                }
+                out[k] = acc;
+            }
+        }
+    }
+}
+
+pub fn forward<T: Float>(buffer: &mut [ComplexT<T>]) -> () {
+    FftPlan::new(buffer.len()).forward(buffer)
+}
+pub fn inverse<T: Float>(buffer: &mut [ComplexT<T>]) -> () {
+    FftPlan::new(buffer.len()).inverse(buffer)
+}
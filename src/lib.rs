@@ -0,0 +1,21 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("complex-proto needs either the `std` or the `libm` feature for its math functions");
+
+extern crate alloc;
+
+mod complex;
+pub mod fft;
+#[cfg(feature = "num")]
+mod num;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod simd;
+mod split;
+
+pub use complex::*;
+pub use split::SplitComplex;
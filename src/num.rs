@@ -0,0 +1,78 @@
+use num_traits::{Inv, Num, One, Pow, Zero};
+
+use crate::complex::{ComplexT, Float, ParseComplexError, Scalar};
+
+impl<T: Scalar> From<num_complex::Complex<T>> for ComplexT<T> {
+    #[inline(always)]
+    fn from(z: num_complex::Complex<T>) -> Self {
+        Self::new(z.re, z.im)
+    }
+}
+impl<T: Scalar> From<ComplexT<T>> for num_complex::Complex<T> {
+    #[inline(always)]
+    fn from(z: ComplexT<T>) -> Self {
+        Self::new(z.real, z.imag)
+    }
+}
+impl<T: Scalar> Zero for ComplexT<T> {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::new(T::ZERO, T::ZERO)
+    }
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.real == T::ZERO && self.imag == T::ZERO
+    }
+}
+impl<T: Scalar> One for ComplexT<T> {
+    #[inline(always)]
+    fn one() -> Self {
+        Self::REAL_UNIT
+    }
+    #[inline(always)]
+    fn is_one(&self) -> bool {
+        *self == Self::REAL_UNIT
+    }
+}
+impl<T: Float + Num> Num for ComplexT<T> {
+    type FromStrRadixErr = ParseComplexError;
+    #[inline(always)]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Self::parse_with(s, |t| T::from_str_radix(t, radix).ok())
+    }
+}
+impl<T: Float> Inv for ComplexT<T> {
+    type Output = Self;
+    #[inline(always)]
+    fn inv(self) -> Self::Output {
+        self.recip()
+    }
+}
+impl<T: Float> Inv for &ComplexT<T> {
+    type Output = ComplexT<T>;
+    #[inline(always)]
+    fn inv(self) -> Self::Output {
+        self.recip()
+    }
+}
+impl<T: Float> Pow<i32> for ComplexT<T> {
+    type Output = Self;
+    #[inline(always)]
+    fn pow(self, exp: i32) -> Self::Output {
+        self.powi(exp)
+    }
+}
+impl<T: Float> Pow<T> for ComplexT<T> {
+    type Output = Self;
+    #[inline(always)]
+    fn pow(self, exp: T) -> Self::Output {
+        self.powf(exp)
+    }
+}
+impl<T: Float> Pow<ComplexT<T>> for ComplexT<T> {
+    type Output = Self;
+    #[inline(always)]
+    fn pow(self, exp: Self) -> Self::Output {
+        ComplexT::pow(self, exp)
+    }
+}
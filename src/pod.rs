@@ -0,0 +1,6 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::complex::ComplexT;
+
+unsafe impl<T: Zeroable> Zeroable for ComplexT<T> {}
+unsafe impl<T: Pod> Pod for ComplexT<T> {}
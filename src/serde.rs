@@ -0,0 +1,67 @@
+//! Wire formats for `ComplexT`, selected with `#[serde(with = "...")]`.
+//!
+//! `ComplexT` itself serializes as `{ "real": .., "imag": .. }`; the modules below
+//! pick that form ([`fields`]), a `[real, imag]` pair ([`tuple`](mod@tuple)) or the `Display` string ([`string`]).
+
+pub mod fields {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::complex::ComplexT;
+
+    #[inline(always)]
+    pub fn serialize<T: Serialize, S: Serializer>(z: &ComplexT<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        z.serialize(serializer)
+    }
+    #[inline(always)]
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<ComplexT<T>, D::Error> {
+        ComplexT::deserialize(deserializer)
+    }
+}
+
+pub mod tuple {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::complex::{ComplexT, Scalar};
+
+    #[inline(always)]
+    pub fn serialize<T: Scalar + Serialize, S: Serializer>(z: &ComplexT<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        (z.real, z.imag).serialize(serializer)
+    }
+    #[inline(always)]
+    pub fn deserialize<'de, T: Scalar + Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<ComplexT<T>, D::Error> {
+        let (real, imag): (T, T) = Deserialize::deserialize(deserializer)?;
+        Ok(ComplexT::new(real, imag))
+    }
+}
+
+pub mod string {
+    use core::fmt;
+    use core::marker::PhantomData;
+    use core::str::FromStr;
+
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    use crate::complex::{ComplexT, Scalar};
+
+    struct ComplexVisitor<T>(PhantomData<T>);
+
+    impl<T: Scalar + FromStr> Visitor<'_> for ComplexVisitor<T> {
+        type Value = ComplexT<T>;
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a complex number string such as \"3+4i\"")
+        }
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+            s.parse::<ComplexT<T>>().map_err(E::custom)
+        }
+    }
+
+    #[inline(always)]
+    pub fn serialize<T: Scalar, S: Serializer>(z: &ComplexT<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(z)
+    }
+    #[inline(always)]
+    pub fn deserialize<'de, T: Scalar + FromStr, D: Deserializer<'de>>(deserializer: D) -> Result<ComplexT<T>, D::Error> {
+        deserializer.deserialize_str(ComplexVisitor(PhantomData))
+    }
+}
@@ -0,0 +1,408 @@
+//! Slice kernels for `Complex` with AVX2/SSE2 paths picked at runtime.
+//!
+//! Every backend performs the same IEEE operations in the same order as the scalar operators,
+//! so results are bitwise identical to the portable path (up to NaN payloads).
+//! With `accurate-mul`, products use the FMA formula of `Complex::mul_accurate`; the AVX2 kernels
+//! follow it and the SSE2 backend is unavailable.
+//! `dot` and `norm_sq_sum` accumulate element `i` into partial sum `i % 4` and
+//! return `(p0 + p2) + (p1 + p3)`, on every backend.
+
+use crate::complex::Complex;
+
+const PARTIALS: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    Portable,
+    Sse2,
+    Avx2,
+}
+impl Backend {
+    pub fn detect() -> Self {
+        if Self::Avx2.is_available() {
+            Self::Avx2
+        } else if Self::Sse2.is_available() {
+            Self::Sse2
+        } else {
+            Self::Portable
+        }
+    }
+    pub fn is_available(self) -> bool {
+        match self {
+            Self::Portable => true,
+            Self::Sse2 => !cfg!(feature = "accurate-mul") && x86::has_sse2(),
+            Self::Avx2 => x86::has_avx2(),
+        }
+    }
+    #[inline(always)]
+    fn checked(self) -> Self {
+        if self.is_available() { self } else { Self::Portable }
+    }
+    pub fn mul_slices(self, a: &[Complex], b: &[Complex], out: &mut [Complex]) -> () {
+        assert!(a.len() == b.len() && a.len() == out.len(), "mul_slices: slices have different lengths");
+        let done: usize = match self.checked() {
+            Self::Portable => 0,
+            Self::Sse2 => unsafe { x86::sse2::mul(a, b, out, false) },
+            Self::Avx2 => unsafe { x86::avx2::mul(a, b, out, false) },
+        };
+        portable::mul(&a[done..], &b[done..], &mut out[done..]);
+    }
+    pub fn conj_mul(self, a: &[Complex], b: &[Complex], out: &mut [Complex]) -> () {
+        assert!(a.len() == b.len() && a.len() == out.len(), "conj_mul: slices have different lengths");
+        let done: usize = match self.checked() {
+            Self::Portable => 0,
+            Self::Sse2 => unsafe { x86::sse2::mul(a, b, out, true) },
+            Self::Avx2 => unsafe { x86::avx2::mul(a, b, out, true) },
+        };
+        portable::conj_mul(&a[done..], &b[done..], &mut out[done..]);
+    }
+    pub fn mul_add(self, a: &[Complex], b: &[Complex], acc: &mut [Complex]) -> () {
+        assert!(a.len() == b.len() && a.len() == acc.len(), "mul_add: slices have different lengths");
+        let done: usize = match self.checked() {
+            Self::Portable => 0,
+            Self::Sse2 => unsafe { x86::sse2::mul_add(a, b, acc) },
+            Self::Avx2 => unsafe { x86::avx2::mul_add(a, b, acc) },
+        };
+        portable::mul_add(&a[done..], &b[done..], &mut acc[done..]);
+    }
+    pub fn scale(self, zs: &mut [Complex], factor: f64) -> () {
+        let done: usize = match self.checked() {
+            Self::Portable => 0,
+            Self::Sse2 => unsafe { x86::sse2::scale(zs, factor) },
+            Self::Avx2 => unsafe { x86::avx2::scale(zs, factor) },
+        };
+        portable::scale(&mut zs[done..], factor);
+    }
+    pub fn dot(self, a: &[Complex], b: &[Complex]) -> Complex {
+        assert!(a.len() == b.len(), "dot: slices have different lengths");
+        let mut partials: [Complex; PARTIALS] = [Complex::default(); PARTIALS];
+        let done: usize = match self.checked() {
+            Self::Portable => 0,
+            Self::Sse2 => unsafe { x86::sse2::dot(a, b, &mut partials) },
+            Self::Avx2 => unsafe { x86::avx2::dot(a, b, &mut partials) },
+        };
+        portable::dot(&a[done..], &b[done..], &mut partials);
+        (partials[0] + partials[2]) + (partials[1] + partials[3])
+    }
+    pub fn norm_sq_sum(self, zs: &[Complex]) -> f64 {
+        let mut partials: [f64; PARTIALS] = [0.0; PARTIALS];
+        let done: usize = match self.checked() {
+            Self::Portable => 0,
+            Self::Sse2 => unsafe { x86::sse2::norm_sq_sum(zs, &mut partials) },
+            Self::Avx2 => unsafe { x86::avx2::norm_sq_sum(zs, &mut partials) },
+        };
+        portable::norm_sq_sum(&zs[done..], &mut partials);
+        (partials[0] + partials[2]) + (partials[1] + partials[3])
+    }
+}
+
+#[inline(always)]
+pub fn mul_slices(a: &[Complex], b: &[Complex], out: &mut [Complex]) -> () {
+    Backend::detect().mul_slices(a, b, out)
+}
+#[inline(always)]
+pub fn conj_mul(a: &[Complex], b: &[Complex], out: &mut [Complex]) -> () {
+    Backend::detect().conj_mul(a, b, out)
+}
+#[inline(always)]
+pub fn mul_add(a: &[Complex], b: &[Complex], acc: &mut [Complex]) -> () {
+    Backend::detect().mul_add(a, b, acc)
+}
+#[inline(always)]
+pub fn scale(zs: &mut [Complex], factor: f64) -> () {
+    Backend::detect().scale(zs, factor)
+}
+#[inline(always)]
+pub fn dot(a: &[Complex], b: &[Complex]) -> Complex {
+    Backend::detect().dot(a, b)
+}
+#[inline(always)]
+pub fn norm_sq_sum(zs: &[Complex]) -> f64 {
+    Backend::detect().norm_sq_sum(zs)
+}
+
+// Kernels take slices whose first element has index `start` in the caller's slice, which is a
+// multiple of `PARTIALS`, so partial sums keep their `i % 4` assignment.
+mod portable {
+    use super::PARTIALS;
+    use crate::complex::Complex;
+
+    pub fn mul(a: &[Complex], b: &[Complex], out: &mut [Complex]) -> () {
+        for ((o, &x), &y) in out.iter_mut().zip(a).zip(b) {
+            *o = x * y;
+        }
+    }
+    pub fn conj_mul(a: &[Complex], b: &[Complex], out: &mut [Complex]) -> () {
+        for ((o, &x), &y) in out.iter_mut().zip(a).zip(b) {
+            *o = x.conj() * y;
+        }
+    }
+    pub fn mul_add(a: &[Complex], b: &[Complex], acc: &mut [Complex]) -> () {
+        for ((o, &x), &y) in acc.iter_mut().zip(a).zip(b) {
+            *o += x * y;
+        }
+    }
+    pub fn scale(zs: &mut [Complex], factor: f64) -> () {
+        for z in zs.iter_mut() {
+            *z *= factor;
+        }
+    }
+    pub fn dot(a: &[Complex], b: &[Complex], partials: &mut [Complex; PARTIALS]) -> () {
+        for (i, (&x, &y)) in a.iter().zip(b).enumerate() {
+            partials[i % PARTIALS] += x * y;
+        }
+    }
+    pub fn norm_sq_sum(zs: &[Complex], partials: &mut [f64; PARTIALS]) -> () {
+        for (i, z) in zs.iter().enumerate() {
+            partials[i % PARTIALS] += z.norm();
+        }
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+mod x86 {
+    #[inline(always)]
+    pub fn has_sse2() -> bool {
+        false
+    }
+    #[inline(always)]
+    pub fn has_avx2() -> bool {
+        false
+    }
+    pub mod sse2 {
+        pub use super::avx2::*;
+    }
+    pub mod avx2 {
+        use super::super::PARTIALS;
+        use crate::complex::Complex;
+
+        pub unsafe fn mul(_: &[Complex], _: &[Complex], _: &mut [Complex], _: bool) -> usize {
+            0
+        }
+        pub unsafe fn mul_add(_: &[Complex], _: &[Complex], _: &mut [Complex]) -> usize {
+            0
+        }
+        pub unsafe fn scale(_: &mut [Complex], _: f64) -> usize {
+            0
+        }
+        pub unsafe fn dot(_: &[Complex], _: &[Complex], _: &mut [Complex; PARTIALS]) -> usize {
+            0
+        }
+        pub unsafe fn norm_sq_sum(_: &[Complex], _: &mut [f64; PARTIALS]) -> usize {
+            0
+        }
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    pub fn has_sse2() -> bool {
+        std::is_x86_feature_detected!("sse2")
+    }
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    pub fn has_avx2() -> bool {
+        std::is_x86_feature_detected!("avx2") && std::is_x86_feature_detected!("fma")
+    }
+    #[cfg(not(feature = "std"))]
+    #[inline(always)]
+    pub fn has_sse2() -> bool {
+        cfg!(target_feature = "sse2")
+    }
+    #[cfg(not(feature = "std"))]
+    #[inline(always)]
+    pub fn has_avx2() -> bool {
+        cfg!(all(target_feature = "avx2", target_feature = "fma"))
+    }
+
+    // Each kernel processes the longest prefix that fills whole vectors (whole groups of
+    // `PARTIALS` elements for the reductions) and returns its length; the caller finishes the tail.
+    pub mod sse2 {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        use super::super::PARTIALS;
+        use crate::complex::Complex;
+
+        #[inline]
+        #[target_feature(enable = "sse2")]
+        unsafe fn cmul(a: __m128d, b: __m128d) -> __m128d {
+            let b_re: __m128d = _mm_unpacklo_pd(b, b);
+            let b_im: __m128d = _mm_unpackhi_pd(b, b);
+            let t1: __m128d = _mm_mul_pd(a, b_re);
+            let t2: __m128d = _mm_mul_pd(_mm_shuffle_pd(a, a, 0b01), b_im);
+            _mm_move_sd(_mm_add_pd(t1, t2), _mm_sub_pd(t1, t2))
+        }
+        #[inline]
+        #[target_feature(enable = "sse2")]
+        unsafe fn conj_mask() -> __m128d {
+            _mm_set_pd(-0.0, 0.0)
+        }
+        #[target_feature(enable = "sse2")]
+        pub unsafe fn mul(a: &[Complex], b: &[Complex], out: &mut [Complex], conj: bool) -> usize {
+            let (pa, pb): (*const f64, *const f64) = (a.as_ptr().cast::<f64>(), b.as_ptr().cast::<f64>());
+            let po: *mut f64 = out.as_mut_ptr().cast::<f64>();
+            let mask: __m128d = if conj { conj_mask() } else { _mm_setzero_pd() };
+            for i in 0..a.len() {
+                let x: __m128d = _mm_xor_pd(_mm_loadu_pd(pa.add(2 * i)), mask);
+                _mm_storeu_pd(po.add(2 * i), cmul(x, _mm_loadu_pd(pb.add(2 * i))));
+            }
+            a.len()
+        }
+        #[target_feature(enable = "sse2")]
+        pub unsafe fn mul_add(a: &[Complex], b: &[Complex], acc: &mut [Complex]) -> usize {
+            let (pa, pb): (*const f64, *const f64) = (a.as_ptr().cast::<f64>(), b.as_ptr().cast::<f64>());
+            let po: *mut f64 = acc.as_mut_ptr().cast::<f64>();
+            for i in 0..a.len() {
+                let prod: __m128d = cmul(_mm_loadu_pd(pa.add(2 * i)), _mm_loadu_pd(pb.add(2 * i)));
+                _mm_storeu_pd(po.add(2 * i), _mm_add_pd(_mm_loadu_pd(po.add(2 * i)), prod));
+            }
+            a.len()
+        }
+        #[target_feature(enable = "sse2")]
+        pub unsafe fn scale(zs: &mut [Complex], factor: f64) -> usize {
+            let p: *mut f64 = zs.as_mut_ptr().cast::<f64>();
+            let k: __m128d = _mm_set1_pd(factor);
+            for i in 0..zs.len() {
+                _mm_storeu_pd(p.add(2 * i), _mm_mul_pd(_mm_loadu_pd(p.add(2 * i)), k));
+            }
+            zs.len()
+        }
+        #[target_feature(enable = "sse2")]
+        pub unsafe fn dot(a: &[Complex], b: &[Complex], partials: &mut [Complex; PARTIALS]) -> usize {
+            let (pa, pb): (*const f64, *const f64) = (a.as_ptr().cast::<f64>(), b.as_ptr().cast::<f64>());
+            let mut acc: [__m128d; PARTIALS] = [_mm_setzero_pd(); PARTIALS];
+            let done: usize = a.len() / PARTIALS * PARTIALS;
+            for i in (0..done).step_by(PARTIALS) {
+                for (k, lane) in acc.iter_mut().enumerate() {
+                    let prod: __m128d = cmul(_mm_loadu_pd(pa.add(2 * (i + k))), _mm_loadu_pd(pb.add(2 * (i + k))));
+                    *lane = _mm_add_pd(*lane, prod);
+                }
+            }
+            for (partial, lane) in partials.iter_mut().zip(acc) {
+                _mm_storeu_pd((partial as *mut Complex).cast::<f64>(), lane);
+            }
+            done
+        }
+        #[target_feature(enable = "sse2")]
+        pub unsafe fn norm_sq_sum(zs: &[Complex], partials: &mut [f64; PARTIALS]) -> usize {
+            let p: *const f64 = zs.as_ptr().cast::<f64>();
+            let mut acc: [__m128d; PARTIALS] = [_mm_setzero_pd(); PARTIALS];
+            let done: usize = zs.len() / PARTIALS * PARTIALS;
+            for i in (0..done).step_by(PARTIALS) {
+                for (k, lane) in acc.iter_mut().enumerate() {
+                    let z: __m128d = _mm_loadu_pd(p.add(2 * (i + k)));
+                    let sq: __m128d = _mm_mul_pd(z, z);
+                    *lane = _mm_add_sd(*lane, _mm_add_sd(sq, _mm_unpackhi_pd(sq, sq)));
+                }
+            }
+            for (partial, lane) in partials.iter_mut().zip(acc) {
+                *partial = _mm_cvtsd_f64(lane);
+            }
+            done
+        }
+    }
+
+    pub mod avx2 {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        use super::super::PARTIALS;
+        use crate::complex::Complex;
+
+        #[cfg(not(feature = "accurate-mul"))]
+        #[inline]
+        #[target_feature(enable = "avx2,fma")]
+        unsafe fn cmul(a: __m256d, b: __m256d) -> __m256d {
+            let b_re: __m256d = _mm256_movedup_pd(b);
+            let b_im: __m256d = _mm256_permute_pd(b, 0b1111);
+            let a_swap: __m256d = _mm256_permute_pd(a, 0b0101);
+            _mm256_addsub_pd(_mm256_mul_pd(a, b_re), _mm256_mul_pd(a_swap, b_im))
+        }
+        // Per lane, f + e with w = (a_swap * b_im) rounded, f = a * b_re -/+ w (fused) and e the
+        // rounding error of -/+ w, with the signs applied to the inputs so signed zeros match.
+        #[cfg(feature = "accurate-mul")]
+        #[inline]
+        #[target_feature(enable = "avx2,fma")]
+        unsafe fn cmul(a: __m256d, b: __m256d) -> __m256d {
+            let b_re: __m256d = _mm256_movedup_pd(b);
+            let b_im: __m256d = _mm256_permute_pd(b, 0b1111);
+            let a_swap: __m256d = _mm256_permute_pd(a, 0b0101);
+            let real_sign: __m256d = _mm256_set_pd(0.0, -0.0, 0.0, -0.0);
+            let w: __m256d = _mm256_xor_pd(_mm256_mul_pd(a_swap, b_im), real_sign);
+            let e: __m256d = _mm256_fmsub_pd(_mm256_xor_pd(a_swap, real_sign), b_im, w);
+            let f: __m256d = _mm256_fmadd_pd(a, b_re, w);
+            _mm256_add_pd(f, e)
+        }
+        #[target_feature(enable = "avx2,fma")]
+        pub unsafe fn mul(a: &[Complex], b: &[Complex], out: &mut [Complex], conj: bool) -> usize {
+            let (pa, pb): (*const f64, *const f64) = (a.as_ptr().cast::<f64>(), b.as_ptr().cast::<f64>());
+            let po: *mut f64 = out.as_mut_ptr().cast::<f64>();
+            let mask: __m256d = if conj { _mm256_set_pd(-0.0, 0.0, -0.0, 0.0) } else { _mm256_setzero_pd() };
+            let done: usize = a.len() / 2 * 2;
+            for i in (0..done).step_by(2) {
+                let x: __m256d = _mm256_xor_pd(_mm256_loadu_pd(pa.add(2 * i)), mask);
+                _mm256_storeu_pd(po.add(2 * i), cmul(x, _mm256_loadu_pd(pb.add(2 * i))));
+            }
+            done
+        }
+        #[target_feature(enable = "avx2,fma")]
+        pub unsafe fn mul_add(a: &[Complex], b: &[Complex], acc: &mut [Complex]) -> usize {
+            let (pa, pb): (*const f64, *const f64) = (a.as_ptr().cast::<f64>(), b.as_ptr().cast::<f64>());
+            let po: *mut f64 = acc.as_mut_ptr().cast::<f64>();
+            let done: usize = a.len() / 2 * 2;
+            for i in (0..done).step_by(2) {
+                let prod: __m256d = cmul(_mm256_loadu_pd(pa.add(2 * i)), _mm256_loadu_pd(pb.add(2 * i)));
+                _mm256_storeu_pd(po.add(2 * i), _mm256_add_pd(_mm256_loadu_pd(po.add(2 * i)), prod));
+            }
+            done
+        }
+        #[target_feature(enable = "avx2,fma")]
+        pub unsafe fn scale(zs: &mut [Complex], factor: f64) -> usize {
+            let p: *mut f64 = zs.as_mut_ptr().cast::<f64>();
+            let k: __m256d = _mm256_set1_pd(factor);
+            let done: usize = zs.len() / 2 * 2;
+            for i in (0..done).step_by(2) {
+                _mm256_storeu_pd(p.add(2 * i), _mm256_mul_pd(_mm256_loadu_pd(p.add(2 * i)), k));
+            }
+            done
+        }
+        #[target_feature(enable = "avx2,fma")]
+        pub unsafe fn dot(a: &[Complex], b: &[Complex], partials: &mut [Complex; PARTIALS]) -> usize {
+            let (pa, pb): (*const f64, *const f64) = (a.as_ptr().cast::<f64>(), b.as_ptr().cast::<f64>());
+            let (mut acc01, mut acc23): (__m256d, __m256d) = (_mm256_setzero_pd(), _mm256_setzero_pd());
+            let done: usize = a.len() / PARTIALS * PARTIALS;
+            for i in (0..done).step_by(PARTIALS) {
+                acc01 = _mm256_add_pd(acc01, cmul(_mm256_loadu_pd(pa.add(2 * i)), _mm256_loadu_pd(pb.add(2 * i))));
+                acc23 = _mm256_add_pd(acc23, cmul(_mm256_loadu_pd(pa.add(2 * i + 4)), _mm256_loadu_pd(pb.add(2 * i + 4))));
+            }
+            let out: *mut f64 = partials.as_mut_ptr().cast::<f64>();
+            _mm256_storeu_pd(out, acc01);
+            _mm256_storeu_pd(out.add(4), acc23);
+            done
+        }
+        #[target_feature(enable = "avx2,fma")]
+        pub unsafe fn norm_sq_sum(zs: &[Complex], partials: &mut [f64; PARTIALS]) -> usize {
+            let p: *const f64 = zs.as_ptr().cast::<f64>();
+            let (mut acc01, mut acc23): (__m256d, __m256d) = (_mm256_setzero_pd(), _mm256_setzero_pd());
+            let done: usize = zs.len() / PARTIALS * PARTIALS;
+            for i in (0..done).step_by(PARTIALS) {
+                let (z01, z23): (__m256d, __m256d) = (_mm256_loadu_pd(p.add(2 * i)), _mm256_loadu_pd(p.add(2 * i + 4)));
+                let (sq01, sq23): (__m256d, __m256d) = (_mm256_mul_pd(z01, z01), _mm256_mul_pd(z23, z23));
+                acc01 = _mm256_add_pd(acc01, _mm256_add_pd(sq01, _mm256_permute_pd(sq01, 0b0101)));
+                acc23 = _mm256_add_pd(acc23, _mm256_add_pd(sq23, _mm256_permute_pd(sq23, 0b0101)));
+            }
+            let mut lanes: [f64; 8] = [0.0; 8];
+            _mm256_storeu_pd(lanes.as_mut_ptr(), acc01);
+            _mm256_storeu_pd(lanes.as_mut_ptr().add(4), acc23);
+            *partials = [lanes[0], lanes[2], lanes[4], lanes[6]];
+            done
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign};
+
+use crate::complex::{ComplexT, Float, Scalar};
+
+#[derive(Default, PartialEq, Debug)]
+pub struct SplitComplex<T = f64> {
+    real: Vec<T>,
+    imag: Vec<T>,
+}
+impl<T: Clone> Clone for SplitComplex<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self { real: self.real.clone(), imag: self.imag.clone() }
+    }
+    #[inline(always)]
+    fn clone_from(&mut self, source: &Self) -> () {
+        self.real.clone_from(&source.real);
+        self.imag.clone_from(&source.imag);
+    }
+}
+impl<T: Scalar> SplitComplex<T> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self { real: Vec::new(), imag: Vec::new() }
+    }
+    #[inline(always)]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { real: Vec::with_capacity(capacity), imag: Vec::with_capacity(capacity) }
+    }
+    #[inline(always)]
+    pub fn from_parts(real: Vec<T>, imag: Vec<T>) -> Option<Self> {
+        if real.len() != imag.len() {
+            return None;
+        }
+        Some(Self { real, imag })
+    }
+    #[inline(always)]
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>) {
+        (self.real, self.imag)
+    }
+    #[inline(always)]
+    pub fn real(&self) -> &[T] {
+        &self.real
+    }
+    #[inline(always)]
+    pub fn imag(&self) -> &[T] {
+        &self.imag
+    }
+    #[inline(always)]
+    pub fn parts_mut(&mut self) -> (&mut [T], &mut [T]) {
+        (&mut self.real, &mut self.imag)
+    }
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.real.len()
+    }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.real.is_empty()
+    }
+    #[inline(always)]
+    pub fn push(&mut self, z: ComplexT<T>) -> () {
+        self.real.push(z.real);
+        self.imag.push(z.imag);
+    }
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<ComplexT<T>> {
+        Some(ComplexT::new(*self.real.get(index)?, self.imag[index]))
+    }
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = ComplexT<T>> + '_ {
+        self.real.iter().zip(&self.imag).map(|(&real, &imag)| ComplexT::new(real, imag))
+    }
+    pub fn conj(mut self) -> Self {
+        for im in self.imag.iter_mut() {
+            *im = -*im;
+        }
+        self
+    }
+    pub fn norm(&self) -> Vec<T> {
+        self.real.iter().zip(&self.imag).map(|(&re, &im)| re * re + im * im).collect()
+    }
+    #[inline(always)]
+    fn check_len(&self, other: &Self) -> () {
+        assert_eq!(self.len(), other.len(), "SplitComplex operands have different lengths");
+    }
+}
+impl<T: Float> SplitComplex<T> {
+    pub fn abs(&self) -> Vec<T> {
+        self.real.iter().zip(&self.imag).map(|(&re, &im)| re.hypot(im)).collect()
+    }
+    pub fn exp(mut self) -> Self {
+        for (re, im) in self.real.iter_mut().zip(self.imag.iter_mut()) {
+            let z: ComplexT<T> = ComplexT::new(*re, *im).exp();
+            *re = z.real;
+            *im = z.imag;
+        }
+        self
+    }
+}
+impl<T: Scalar> AddAssign<&SplitComplex<T>> for SplitComplex<T> {
+    fn add_assign(&mut self, other: &Self) -> () {
+        self.check_len(other);
+        for (a, &b) in self.real.iter_mut().zip(&other.real) {
+            *a += b;
+        }
+        for (a, &b) in self.imag.iter_mut().zip(&other.imag) {
+            *a += b;
+        }
+    }
+}
+impl<T: Scalar> SubAssign<&SplitComplex<T>> for SplitComplex<T> {
+    fn sub_assign(&mut self, other: &Self) -> () {
+        self.check_len(other);
+        for (a, &b) in self.real.iter_mut().zip(&other.real) {
+            *a -= b;
+        }
+        for (a, &b) in self.imag.iter_mut().zip(&other.imag) {
+            *a -= b;
+        }
+    }
+}
+impl<T: Scalar> MulAssign<&SplitComplex<T>> for SplitComplex<T> {
+    fn mul_assign(&mut self, other: &Self) -> () {
+        self.check_len(other);
+        let lanes = self.real.iter_mut().zip(self.imag.iter_mut()).zip(other.real.iter().zip(&other.imag));
+        for ((ar, ai), (&br, &bi)) in lanes {
+            let real: T = T::diff_of_products(*ar, br, *ai, bi);
+            let imag: T = T::sum_of_products(*ai, br, *ar, bi);
+            *ar = real;
+            *ai = imag;
+        }
+    }
+}
+impl<T: Float> DivAssign<&SplitComplex<T>> for SplitComplex<T> {
+    fn div_assign(&mut self, other: &Self) -> () {
+        self.check_len(other);
+        let lanes = self.real.iter_mut().zip(self.imag.iter_mut()).zip(other.real.iter().zip(&other.imag));
+        for ((ar, ai), (&br, &bi)) in lanes {
+            let z: ComplexT<T> = ComplexT::new(*ar, *ai) / ComplexT::new(br, bi);
+            *ar = z.real;
+            *ai = z.imag;
+        }
+    }
+}
+macro_rules! forward_split_binop {
+    (impl<T: $bound:ident> $imp:ident, $method:ident, $assign:ident) => {
+        impl<T: $bound> $imp<&SplitComplex<T>> for SplitComplex<T> {
+            type Output = Self;
+            #[inline(always)]
+            fn $method(mut self, other: &Self) -> Self::Output {
+                self.$assign(other);
+                self
+            }
+        }
+        impl<T: $bound> $imp for SplitComplex<T> {
+            type Output = Self;
+            #[inline(always)]
+            fn $method(mut self, other: Self) -> Self::Output {
+                self.$assign(&other);
+                self
+            }
+        }
+        impl<T: $bound> $imp for &SplitComplex<T> {
+            type Output = SplitComplex<T>;
+            #[inline(always)]
+            fn $method(self, other: Self) -> Self::Output {
+                let mut out: SplitComplex<T> = self.clone();
+                out.$assign(other);
+                out
+            }
+        }
+    };
+}
+forward_split_binop!(impl<T: Scalar> Add, add, add_assign);
+forward_split_binop!(impl<T: Scalar> Sub, sub, sub_assign);
+forward_split_binop!(impl<T: Scalar> Mul, mul, mul_assign);
+forward_split_binop!(impl<T: Float> Div, div, div_assign);
+impl<T: Scalar> FromIterator<ComplexT<T>> for SplitComplex<T> {
+    fn from_iter<I: IntoIterator<Item = ComplexT<T>>>(iter: I) -> Self {
+        let mut out: Self = Self::new();
+        out.extend(iter);
+        out
+    }
+}
+impl<T: Scalar> Extend<ComplexT<T>> for SplitComplex<T> {
+    fn extend<I: IntoIterator<Item = ComplexT<T>>>(&mut self, iter: I) -> () {
+        let iter = iter.into_iter();
+        self.real.reserve(iter.size_hint().0);
+        self.imag.reserve(iter.size_hint().0);
+        for z in iter {
+            self.push(z);
+        }
+    }
+}
+impl<T: Scalar> From<&[ComplexT<T>]> for SplitComplex<T> {
+    #[inline(always)]
+    fn from(zs: &[ComplexT<T>]) -> Self {
+        zs.iter().copied().collect()
+    }
+}
+impl<T: Scalar> From<Vec<ComplexT<T>>> for SplitComplex<T> {
+    #[inline(always)]
+    fn from(zs: Vec<ComplexT<T>>) -> Self {
+        Self::from(zs.as_slice())
+    }
+}
+impl<T: Scalar> From<SplitComplex<T>> for Vec<ComplexT<T>> {
+    #[inline(always)]
+    fn from(split: SplitComplex<T>) -> Self {
+        split.iter().collect()
+    }
+}
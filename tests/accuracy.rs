@@ -0,0 +1,437 @@
+#![allow(clippy::approx_constant)]
+
+use complex_proto::Complex;
+
+// Reference values computed with mpmath (50 significant digits, 1200 for the inverse functions) and rounded to f64.
+// The suite is run against both math backends:
+//   cargo test --test accuracy
+//   cargo test --test accuracy --no-default-features --features libm
+type Case = ((f64, f64), (f64, f64));
+
+const EXP: [Case; 10] = [
+    ((0.5, 0.25), (1.5974665191199127, 0.40790017007835977)),
+    ((1.5, -2.0), (-1.8650407290090891, -4.075188339491184)),
+    ((-3.0, 0.75), (0.036428643738625956, 0.033936795547467744)),
+    ((-0.2, -1.3), (0.2190095174072864, -0.7888947187810721)),
+    ((10.0, 5.0), (6248.075425385872, -21121.71273567746)),
+    ((2.0, 30.0), (1.1397726165607485, -7.300621097939716)),
+    ((-0.75, 0.5), (0.41454064950578356, 0.22646458896647165)),
+    ((0.1, -0.05), (1.103789742208997, -0.05523552438753377)),
+    ((4.0, -0.001), (54.598122734071495, -0.05459814093345302)),
+    ((-25.0, -3.0), (-1.3748960219522571e-11, -1.959866750158706e-12)),
+];
+const LN: [Case; 10] = [
+    ((0.5, 0.25), (-0.5815754049028404, 0.4636476090008061)),
+    ((1.5, -2.0), (0.9162907318741551, -0.9272952180016122)),
+    ((-3.0, 0.75), (1.128924599576327, 2.896613990462929)),
+    ((-0.2, -1.3), (0.2740607042548438, -1.7234456551901618)),
+    ((10.0, 5.0), (2.4141568686511508, 0.4636476090008061)),
+    ((2.0, 30.0), (3.403414680196088, 1.5042281630190728)),
+    ((-0.75, 0.5), (-0.10381968238912224, 2.5535900500422257)),
+    ((0.1, -0.05), (-2.1910133173369406, -0.4636476090008061)),
+    ((4.0, -0.001), (1.3862943923698896, -0.0002499999947916669)),
+    ((-25.0, -3.0), (3.226024477218613, -3.0221637275714546)),
+];
+const SQRT: [Case; 10] = [
+    ((0.5, 0.25), (0.7276733451126774, 0.17178037486125622)),
+    ((1.5, -2.0), (1.4142135623730951, -0.7071067811865476)),
+    ((-3.0, 0.75), (0.21485951132454598, 1.745326505157881)),
+    ((-0.2, -1.3), (0.7467578736767998, -0.870429389381066)),
+    ((10.0, 5.0), (3.254254130173222, 0.7682251907803299)),
+    ((2.0, 30.0), (4.004159884217026, 3.7461041601072584)),
+    ((-0.75, 0.5), (0.27512526135016874, 0.9086770105119854)),
+    ((0.1, -0.05), (0.3254254130173222, -0.07682251907803299)),
+    ((4.0, -0.001), (2.0000000156249995, -0.00024999999804687506)),
+    ((-25.0, -3.0), (0.2994633734101243, -5.008959803393732)),
+];
+const SIN: [Case; 10] = [
+    ((0.5, 0.25), (0.494485780933195, 0.22168816414957482)),
+    ((1.5, -2.0), (3.752771340479298, -0.2565539560904818)),
+    ((-3.0, 0.75), (-0.18270571556857168, -0.8140873944454716)),
+    ((-0.2, -1.3), (-0.39156021119345447, -1.6645278631830411)),
+    ((10.0, 5.0), (-40.37177863549803, -62.26180136188721)),
+    ((2.0, 30.0), (4858591919409.123, -2223571295471.4263)),
+    ((-0.75, 0.5), (-0.7686335646933927, 0.3812796346521781)),
+    ((0.1, -0.05), (0.0999582344180889, -0.04977094010865551)),
+    ((4.0, -0.001), (-0.7568028737092074, 0.0006536437298042208)),
+    ((-25.0, -3.0), (1.3324726845340258, -9.929745796945285)),
+];
+const COS: [Case; 10] = [
+    ((0.5, 0.25), (0.9051501505596067, -0.12110879604381165)),
+    ((1.5, -2.0), (0.2661271953135458, 3.6177750739401375)),
+    ((-3.0, 0.75), (-1.2817267373040948, 0.116045343838548)),
+    ((-0.2, -1.3), (1.9316271649348236, -0.33741650225100905)),
+    ((10.0, 5.0), (-62.26745498137856, 40.36811305008648)),
+    ((2.0, 30.0), (-2223571295471.4263, -4858591919409.123)),
+    ((-0.75, 0.5), (0.8250713669946073, 0.35519875789073846)),
+    ((0.1, -0.05), (0.9962481796218853, 0.004993750955186549)),
+    ((4.0, -0.001), (-0.6536439476854495, -0.0007568026214416838)),
+    ((-25.0, -3.0), (9.979094879105952, 1.3258832789033015)),
+];
+const TAN: [Case; 10] = [
+    ((0.5, 0.25), (0.504500702698564, 0.3124206925025888)),
+    ((1.5, -2.0), (0.0053620609220030565, -1.036920282100185)),
+    ((-3.0, 0.75), (0.08434981388349302, 0.6427858400872473)),
+    ((-0.2, -1.3), (-0.05063913648678776, -0.870568800242652)),
+    ((10.0, 5.0), (8.289222887763953e-05, 0.9999629434569673)),
+    ((2.0, 30.0), (-1.3253898390798914e-26, 1.0)),
+    ((-0.75, 0.5), (-0.6180963948062022, 0.7282118012804724)),
+    ((0.1, -0.05), (0.10008173824821713, -0.05046004039245417)),
+    ((4.0, -0.001), (1.1578185724162735, -0.002340546204061151)),
+    ((-25.0, -3.0), (0.001294523752739411, -0.995226751991055)),
+];
+const SINH: [Case; 10] = [
+    ((0.5, 0.25), (0.504895714387995, 0.2789791283502615)),
+    ((1.5, -2.0), (-0.8860929093625314, -2.139040009980677)),
+    ((-3.0, 0.75), (-7.329967574155962, 6.862508639136002)),
+    ((-0.2, -1.3), (-0.05385714483969959, -0.9828936720487199)),
+    ((10.0, 5.0), (3124.0377062538146, -10560.856389606277)),
+    ((2.0, 30.0), (0.5594484764502771, -3.717168318816527)),
+    ((-0.75, 0.5), (-0.7216508242975646, 0.620704231078055)),
+    ((0.1, -0.05), (0.10004156766523671, -0.05022927343299826)),
+    ((4.0, -0.001), (27.28990355217029, -0.027308228284644576)),
+    ((-25.0, -3.0), (35642155031.2412, -5080665987.420911)),
+];
+const COSH: [Case; 10] = [
+    ((0.5, 0.25), (1.0925708047319176, 0.12892104172809826)),
+    ((1.5, -2.0), (-0.9789478196465577, -1.9361483295105073)),
+    ((-3.0, 0.75), (7.366396217894588, -6.828571843588534)),
+    ((-0.2, -1.3), (0.272866662246986, 0.19399895326764788)),
+    ((10.0, 5.0), (3124.0377191320576, -10560.856346071183)),
+    ((2.0, 30.0), (0.5803241401104714, -3.583452779123189)),
+    ((-0.75, 0.5), (1.136191473803348, -0.3942396421115833)),
+    ((0.1, -0.05), (1.0037481745437602, -0.005006250954535507)),
+    ((4.0, -0.001), (27.308219181901208, -0.027289912648808447)),
+    ((-25.0, -3.0), (-35642155031.2412, 5080665987.420911)),
+];
+const TANH: [Case; 10] = [
+    ((0.5, 0.25), (0.48548728102413535, 0.19805544995134952)),
+    ((1.5, -2.0), (1.0641443991765371, 0.08039101531016818)),
+    ((-3.0, 0.75), (-0.9996371610597968, 0.004943321841667708)),
+    ((-0.2, -1.3), (-1.8322138281423281, -2.2994604839585118)),
+    ((10.0, 5.0), (1.0000000034589107, -2.2426221745423757e-09)),
+    ((2.0, 30.0), (1.0354417865444585, -0.011565211128783616)),
+    ((-0.75, 0.5), (-0.736084170551191, 0.2908934618296181)),
+    ((0.1, -0.05), (0.0999150950237821, -0.04954337617178393)),
+    ((4.0, -0.001), (0.9993293010791179, -1.3409497908571007e-06)),
+    ((-25.0, -3.0), (-1.0, 1.0778451993398812e-22)),
+];
+
+const ASIN: [Case; 25] = [
+    ((0.5, 0.25), (0.5016088532755008, 0.2813960562452928)),
+    ((1.5, -2.0), (0.6065115181997548, -1.6224941488715938)),
+    ((-3.0, 0.75), (-1.3126000435075036, 1.7984283835761978)),
+    ((-0.2, -1.3), (-0.12167341979867956, -1.0843408574505276)),
+    ((10.0, 5.0), (1.105542948483495, 3.106105754289201)),
+    ((2.0, 30.0), (0.06653148393443185, 4.096835851484242)),
+    ((-0.75, 0.5), (-0.6822039655834323, 0.6063349998873513)),
+    ((0.1, -0.05), (0.10004076630348603, -0.050230126520870104)),
+    ((4.0, -0.001), (1.570538127911461, -2.063437103322078)),
+    ((-25.0, -3.0), (-1.4512739996188224, -3.9187883239896766)),
+    ((0.5, 1e-10), (0.5235987755982989, 1.1547005383792516e-10)),
+    ((1.0, 1e-20), (1.5707963266948965, 1e-10)),
+    ((-1.0, 1e-08), (-1.57069632679498, 0.00010000000008333333)),
+    ((1e-10, 1e-12), (1e-10, 1e-12)),
+    ((0.9999, 1e-05), (1.5566364510441024, 0.000706244427749437)),
+    ((1.5, 1e-17), (1.5707963267948966, 0.9624236501192069)),
+    ((1e-300, 1.0), (7.071067811865475e-301, 0.881373587019543)),
+    ((1e-09, 1.0000001), (7.071067458312093e-10, 0.8813736577302195)),
+    ((1e+200, 1e+200), (0.7853981633974483, 461.55673936964905)),
+    ((-1e+300, 5.0), (-1.5707963267948966, 691.4686750787737)),
+    ((3e-05, -2e-06), (3.0000000004440002e-05, -2.0000000008986667e-06)),
+    ((0.999999999, -1e-300), (1.5707516054359754, -2.236068009678968e-296)),
+    ((1e-20, 2.0), (4.472135954999579e-21, 1.4436354751788103)),
+    ((-1e+18, 0.001), (-1.5707963267948966, 42.13967885445277)),
+    ((0.6, 0.8), (0.4636476090008061, 0.8047189562170503)),
+];
+const ACOS: [Case; 25] = [
+    ((0.5, 0.25), (1.0691874735193958, -0.2813960562452928)),
+    ((1.5, -2.0), (0.9642848085951419, 1.6224941488715938)),
+    ((-3.0, 0.75), (2.8833963703024, -1.7984283835761978)),
+    ((-0.2, -1.3), (1.692469746593576, 1.0843408574505276)),
+    ((10.0, 5.0), (0.46525337831140146, -3.106105754289201)),
+    ((2.0, 30.0), (1.5042648428604648, -4.096835851484242)),
+    ((-0.75, 0.5), (2.2530002923783288, -0.6063349998873513)),
+    ((0.1, -0.05), (1.4707555604914107, 0.050230126520870104)),
+    ((4.0, -0.001), (0.00025819888343563303, 2.063437103322078)),
+    ((-25.0, -3.0), (3.022070326413719, 3.9187883239896766)),
+    ((0.5, 1e-10), (1.0471975511965979, -1.1547005383792516e-10)),
+    ((1.0, 1e-20), (1e-10, -1e-10)),
+    ((-1.0, 1e-08), (3.1414926535898764, -0.00010000000008333333)),
+    ((1e-10, 1e-12), (1.5707963266948965, -1e-12)),
+    ((0.9999, 1e-05), (0.014159875750794214, -0.000706244427749437)),
+    ((1.5, 1e-17), (8.944271909999159e-18, -0.9624236501192069)),
+    ((1e-300, 1.0), (1.5707963267948966, -0.881373587019543)),
+    ((1e-09, 1.0000001), (1.57079632608779, -0.8813736577302195)),
+    ((1e+200, 1e+200), (0.7853981633974483, -461.55673936964905)),
+    ((-1e+300, 5.0), (3.141592653589793, -691.4686750787737)),
+    ((3e-05, -2e-06), (1.5707663267948921, 2.0000000008986667e-06)),
+    ((0.999999999, -1e-300), (4.4721358921319356e-05, 2.236068009678968e-296)),
+    ((1e-20, 2.0), (1.5707963267948966, -1.4436354751788103)),
+    ((-1e+18, 0.001), (3.141592653589793, -42.13967885445277)),
+    ((0.6, 0.8), (1.1071487177940906, -0.8047189562170503)),
+];
+const ATAN: [Case; 25] = [
+    ((0.5, 0.25), (0.4842544903299662, 0.20058661813123432)),
+    ((1.5, -2.0), (1.311223269671635, -0.3104282830771958)),
+    ((-3.0, 0.75), (-1.2651884866374963, 0.0714891116210778)),
+    ((-0.2, -1.3), (-1.3201641943591054, -0.9283930166760769)),
+    ((10.0, 5.0), (1.490839765215787, 0.03976617365742184)),
+    ((2.0, 30.0), (1.5685815018571512, 0.03319786849545302)),
+    ((-0.75, 0.5), (-0.7232206661240675, 0.3104282830771958)),
+    ((0.1, -0.05), (0.09991432253649511, -0.04954423214593718)),
+    ((4.0, -0.001), (1.3258176775088621, -5.8823526222946086e-05)),
+    ((-25.0, -3.0), (-1.5313836885578145, -0.004724550055057379)),
+    ((0.5, 1e-10), (0.4636476090008061, 8.000000000000001e-11)),
+    ((1.0, 1e-20), (0.7853981633974483, 5e-21)),
+    ((-1.0, 1e-08), (-0.7853981633974484, 5e-09)),
+    ((1e-10, 1e-12), (1e-10, 1e-12)),
+    ((0.9999, 1e-05), (0.7853481609223675, 5.000500024916667e-06)),
+    ((1.5, 1e-17), (0.982793723247329, 3.0769230769230773e-18)),
+    ((1e-300, 1.0), (0.7853981633974483, 345.73433753938684)),
+    ((1e-09, 1.0000001), (1.565796493704483, 8.405596441717144)),
+    ((1e+200, 1e+200), (1.5707963267948966, 5e-201)),
+    ((-1e+300, 5.0), (-1.5707963267948966, 0.0)),
+    ((3e-05, -2e-06), (2.9999999991120002e-05, -1.9999999982026667e-06)),
+    ((0.999999999, -1e-300), (0.7853981628974483, -5.000000005e-301)),
+    ((1e-20, 2.0), (1.5707963267948966, 0.5493061443340549)),
+    ((-1e+18, 0.001), (-1.5707963267948966, 1.0000000000000001e-39)),
+    ((0.6, 0.8), (0.7853981633974483, 0.5493061443340549)),
+];
+const ASINH: [Case; 25] = [
+    ((0.5, 0.25), (0.4926756834207706, 0.2243284526346675)),
+    ((1.5, -2.0), (1.6004100552346137, -0.8877651461839051)),
+    ((-3.0, 0.75), (-1.8445760096309898, 0.2334714490256083)),
+    ((-0.2, -1.3), (-0.796819632258264, -1.342538415021146)),
+    ((10.0, 5.0), (3.108505704369079, 0.4620533595396763)),
+    ((2.0, 30.0), (4.096287648675977, 1.5041914228040667)),
+    ((-0.75, 0.5), (-0.7433204263252785, 0.39827787353830835)),
+    ((0.1, -0.05), (0.09995745236131644, -0.04977179521182948)),
+    ((4.0, -0.001), (2.0947125757947034, -0.00024253562070034442)),
+    ((-25.0, -3.0), (-3.9195545773206204, -0.11933573932090172)),
+    ((0.5, 1e-10), (0.48121182505960347, 8.944271909999159e-11)),
+    ((1.0, 1e-20), (0.881373587019543, 7.071067811865475e-21)),
+    ((-1.0, 1e-08), (-0.881373587019543, 7.071067811865475e-09)),
+    ((1e-10, 1e-12), (1e-10, 1e-12)),
+    ((0.9999, 1e-05), (0.8813028745913065, 7.071421374065003e-06)),
+    ((1.5, 1e-17), (1.1947632172871092, 5.547001962252292e-18)),
+    ((1e-300, 1.0), (1e-150, 1.5707963267948966)),
+    ((1e-09, 1.0000001), (0.0004472191818991297, 1.5707940907549254)),
+    ((1e+200, 1e+200), (461.55673936964905, 0.7853981633974483)),
+    ((-1e+300, 5.0), (-691.4686750787737, 5e-300)),
+    ((3e-05, -2e-06), (2.999999999556e-05, -1.999999999101333e-06)),
+    ((0.999999999, -1e-300), (0.8813735863124362, -7.071067815401009e-301)),
+    ((1e-20, 2.0), (1.3169578969248168, 1.5707963267948966)),
+    ((-1e+18, 0.001), (-42.13967885445277, 1.0000000000000001e-21)),
+    ((0.6, 0.8), (0.7127084715353063, 0.684719203002283)),
+];
+const ACOSH: [Case; 25] = [
+    ((0.5, 0.25), (0.2813960562452928, 1.0691874735193958)),
+    ((1.5, -2.0), (1.6224941488715938, -0.9642848085951419)),
+    ((-3.0, 0.75), (1.7984283835761978, 2.8833963703024)),
+    ((-0.2, -1.3), (1.0843408574505276, -1.692469746593576)),
+    ((10.0, 5.0), (3.106105754289201, 0.46525337831140146)),
+    ((2.0, 30.0), (4.096835851484242, 1.5042648428604648)),
+    ((-0.75, 0.5), (0.6063349998873513, 2.2530002923783288)),
+    ((0.1, -0.05), (0.050230126520870104, -1.4707555604914107)),
+    ((4.0, -0.001), (2.063437103322078, -0.00025819888343563303)),
+    ((-25.0, -3.0), (3.9187883239896766, -3.022070326413719)),
+    ((0.5, 1e-10), (1.1547005383792516e-10, 1.0471975511965979)),
+    ((1.0, 1e-20), (1e-10, 1e-10)),
+    ((-1.0, 1e-08), (0.00010000000008333333, 3.1414926535898764)),
+    ((1e-10, 1e-12), (1e-12, 1.5707963266948965)),
+    ((0.9999, 1e-05), (0.000706244427749437, 0.014159875750794214)),
+    ((1.5, 1e-17), (0.9624236501192069, 8.944271909999159e-18)),
+    ((1e-300, 1.0), (0.881373587019543, 1.5707963267948966)),
+    ((1e-09, 1.0000001), (0.8813736577302195, 1.57079632608779)),
+    ((1e+200, 1e+200), (461.55673936964905, 0.7853981633974483)),
+    ((-1e+300, 5.0), (691.4686750787737, 3.141592653589793)),
+    ((3e-05, -2e-06), (2.0000000008986667e-06, -1.5707663267948921)),
+    ((0.999999999, -1e-300), (2.236068009678968e-296, -4.4721358921319356e-05)),
+    ((1e-20, 2.0), (1.4436354751788103, 1.5707963267948966)),
+    ((-1e+18, 0.001), (42.13967885445277, 3.141592653589793)),
+    ((0.6, 0.8), (0.8047189562170503, 1.1071487177940906)),
+];
+const ATANH: [Case; 25] = [
+    ((0.5, 0.25), (0.5003700000525311, 0.3143981432077165)),
+    ((1.5, -2.0), (0.22008968066202292, -1.2452579660726568)),
+    ((-3.0, 0.75), (-0.32231759620945777, 1.4840849666574578)),
+    ((-0.2, -1.3), (-0.07379118424361317, -0.9222590973935441)),
+    ((10.0, 5.0), (0.08004188189906733, 1.5305608211621662)),
+    ((2.0, 30.0), (0.0022099591422852297, 1.5376224984884392)),
+    ((-0.75, 0.5), (-0.5902135002795054, 0.6927241883996009)),
+    ((0.1, -0.05), (0.10008092715193644, -0.05046089233364693)),
+    ((4.0, -0.001), (0.2554127941052189, -1.5707296601330694)),
+    ((-25.0, -3.0), (-0.0394517477575922, -1.5660571322299655)),
+    ((0.5, 1e-10), (0.5493061443340549, 1.3333333333333334e-10)),
+    ((1.0, 1e-20), (23.37242452022043, 0.7853981633974483)),
+    ((-1.0, 1e-08), (-9.556913962256155, 0.7853981658974483)),
+    ((1e-10, 1e-12), (1e-10, 1e-12)),
+    ((0.9999, 1e-05), (4.949231192936057, 0.049836826370592696)),
+    ((1.5, 1e-17), (0.8047189562170501, 1.5707963267948966)),
+    ((1e-300, 1.0), (5e-301, 0.7853981633974483)),
+    ((1e-09, 1.0000001), (4.999999500000025e-10, 0.7853982133974459)),
+    ((1e+200, 1e+200), (5e-201, 1.5707963267948966)),
+    ((-1e+300, 5.0), (-1e-300, 1.5707963267948966)),
+    ((3e-05, -2e-06), (3.000000000888e-05, -2.000000001797333e-06)),
+    ((0.999999999, -1e-300), (10.708206522644144, -5.000000143909662e-292)),
+    ((1e-20, 2.0), (2e-21, 1.1071487177940904)),
+    ((-1e+18, 0.001), (-1e-18, 1.5707963267948966)),
+    ((0.6, 0.8), (0.34657359027997264, 0.7853981633974483)),
+];
+
+const LN_UNIT_CIRCLE: [Case; 12] = [
+    ((0.6, 0.8), (2.2204460492503132e-17, 0.9272952180016123)),
+    ((0.6, 0.8000000001), (8.000002882229018e-11, 0.9272952180616123)),
+    ((1.0, 1e-08), (5e-17, 1e-08)),
+    ((1.000000000001, 1e-09), (1.0000894005818409e-12, 9.99999999999e-10)),
+    ((0.7071067811865476, 0.7071067811865475), (-1.014653635756952e-17, 0.7853981633974483)),
+    ((0.5403023058681398, 0.8414709848078965), (2.4228383963033683e-17, 1.0)),
+    ((-0.8, 0.6000000000000001), (8.881784197001252e-17, 2.498091544796509)),
+    ((1.9, 0.1), (0.6432370129188398, 0.05258306161094172)),
+    ((0.5, 0.3), (-0.539404830685965, 0.5404195002705842)),
+    ((0.001, 0.9999995), (1.2504113331920754e-13, 1.56979632662823)),
+    ((-0.9999999999999999, 0.0), (-1.1102230246251565e-16, 3.141592653589793)),
+    ((0.28, -0.96), (-2.6645352591003756e-17, -1.2870022175865687)),
+];
+const LN_1P: [Case; 10] = [
+    ((1e-10, 1e-10), (1e-10, 9.999999999e-11)),
+    ((-1e-05, 0.003), (-5.499980249671832e-06, 0.0030000210000785997)),
+    ((1e-300, 0.5), (0.11157177565710488, 0.4636476090008061)),
+    ((-0.5, 0.5), (-0.34657359027997264, 0.7853981633974483)),
+    ((0.3, -0.2), (0.2740607042548438, -0.15264932839526518)),
+    ((-2e-08, 0.0002), (2.0000000149841819e-16, 0.00020000000133333333)),
+    ((1.0, 1.0), (0.8047189562170501, 0.4636476090008061)),
+    ((-0.9, 0.1), (-1.9560115027140732, 0.7853981633974485)),
+    ((3.0, -4.0), (1.7328679513998633, -0.7853981633974483)),
+    ((-1e-17, -1e-20), (-1e-17, -1e-20)),
+];
+const EXP_M1: [Case; 10] = [
+    ((1e-10, 1e-10), (1e-10, 1.0000000001000001e-10)),
+    ((1e-05, -0.003), (5.500008374974405e-06, -0.003000025500107025)),
+    ((-0.5, 0.5), (-0.46771926978432926, 0.29078628821269187)),
+    ((0.9, 1.0), (0.3289292324785503, 2.0696846521818046)),
+    ((1e-20, 1e-10), (4.999999999999999e-21, 1e-10)),
+    ((0.0, 1e-08), (-5e-17, 1e-08)),
+    ((-1e-08, 1.0), (-0.4596976995348833, 0.8414709763931867)),
+    ((3.0, 2.0), (-9.358532650935372, 18.263727040666765)),
+    ((-1.0, -3.0), (-1.3641978864132929, -0.05191514970317339)),
+    ((0.005, 1e-05), (0.005012520809150437, 1.005012520842651e-05)),
+];
+
+fn check(name: &str, f: fn(Complex) -> Complex, cases: &[Case], max_eps: f64) {
+    for &((x, y), (u, v)) in cases {
+        let expected: Complex = Complex::new(u, v);
+        let actual: Complex = f(Complex::new(x, y));
+        let err: f64 = (actual - expected).abs() / expected.abs() / f64::EPSILON;
+        assert!(
+            err <= max_eps,
+            "{}({:?}) = {:?}, expected {:?} (error {:.1} eps)", name, Complex::new(x, y), actual, expected, err,
+        );
+    }
+}
+
+#[test]
+fn exp() {
+    check("exp", Complex::exp, &EXP, 2.0);
+}
+
+#[test]
+fn ln() {
+    check("ln", Complex::ln, &LN, 2.0);
+}
+
+#[test]
+fn sqrt() {
+    check("sqrt", Complex::sqrt, &SQRT, 2.0);
+}
+
+#[test]
+fn sin() {
+    check("sin", Complex::sin, &SIN, 2.0);
+}
+
+#[test]
+fn cos() {
+    check("cos", Complex::cos, &COS, 2.0);
+}
+
+#[test]
+fn tan() {
+    check("tan", |z| z.tan(), &TAN, 2.0);
+}
+
+#[test]
+fn sinh() {
+    check("sinh", Complex::sinh, &SINH, 2.0);
+}
+
+#[test]
+fn cosh() {
+    check("cosh", Complex::cosh, &COSH, 2.0);
+}
+
+#[test]
+fn tanh() {
+    check("tanh", Complex::tanh, &TANH, 2.0);
+}
+
+// Inverse functions are checked per component, including the points near the branch points,
+// the real axis and the overflow thresholds that the textbook formulas get wrong.
+fn check_components(name: &str, f: fn(Complex) -> Complex, cases: &[Case], max_eps: f64) {
+    for &((x, y), (u, v)) in cases {
+        let actual: Complex = f(Complex::new(x, y));
+        for (a, e) in [(actual.real, u), (actual.imag, v)] {
+            let err: f64 = (a - e).abs() / (e.abs() * f64::EPSILON).max(f64::MIN_POSITIVE);
+            assert!(
+                err <= max_eps,
+                "{}({:?}) = {:?}, expected {:?} (error {:.1} eps)", name, Complex::new(x, y), actual, Complex::new(u, v), err,
+            );
+        }
+    }
+}
+
+#[test]
+fn asin() {
+    check_components("asin", Complex::asin, &ASIN, 2.0);
+}
+
+#[test]
+fn acos() {
+    check_components("acos", Complex::acos, &ACOS, 2.0);
+}
+
+#[test]
+fn atan() {
+    check_components("atan", Complex::atan, &ATAN, 2.0);
+}
+
+#[test]
+fn asinh() {
+    check_components("asinh", Complex::asinh, &ASINH, 2.0);
+}
+
+#[test]
+fn acosh() {
+    check_components("acosh", Complex::acosh, &ACOSH, 2.0);
+}
+
+#[test]
+fn atanh() {
+    check_components("atanh", Complex::atanh, &ATANH, 2.0);
+}
+
+#[test]
+fn ln_near_unit_circle() {
+    check_components("ln", Complex::ln, &LN_UNIT_CIRCLE, 2.0);
+}
+
+#[test]
+fn ln_1p() {
+    check_components("ln_1p", Complex::ln_1p, &LN_1P, 2.0);
+}
+
+#[test]
+fn exp_m1() {
+    check_components("exp_m1", Complex::exp_m1, &EXP_M1, 2.0);
+}
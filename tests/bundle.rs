@@ -0,0 +1,37 @@
+use std::{env, fs};
+
+include!("../complex.rs");
+
+const SOURCE: &str = include_str!("../src/complex.rs");
+const BUNDLE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/complex.rs");
+
+fn render(source: &str) -> String {
+    let mut out: String = String::from("#[allow(unexpected_cfgs, clippy::unused_unit)]\nmod complex {\n");
+    for line in source.lines() {
+        if !line.is_empty() {
+            out.push_str("    ");
+            out.push_str(line);
+        }
+        out.push('\n');
+    }
+    out.push_str("}\nuse complex::Complex;\n");
+    out
+}
+
+#[test]
+fn bundle_matches_source() {
+    let expected: String = render(SOURCE);
+    if env::var_os("UPDATE_BUNDLE").is_some() {
+        fs::write(BUNDLE_PATH, &expected).unwrap();
+        return;
+    }
+    let actual: String = fs::read_to_string(BUNDLE_PATH).unwrap();
+    assert!(actual == expected, "complex.rs is out of date with src/complex.rs, rerun with UPDATE_BUNDLE=1");
+}
+
+#[test]
+fn bundle_is_usable() {
+    let z: Complex = Complex::new(3.0, 4.0);
+    assert_eq!(z.abs(), 5.0);
+    assert_eq!(z * z.conj(), Complex::new(25.0, 0.0));
+}
@@ -0,0 +1,40 @@
+#![allow(dead_code)]
+
+use complex_proto::Complex;
+
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    pub fn uniform(&mut self, lo: f64, hi: f64) -> f64 {
+        lo + (hi - lo) * ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64)
+    }
+    pub fn complex(&mut self, lo: f64, hi: f64) -> Complex {
+        Complex::new(self.uniform(lo, hi), self.uniform(lo, hi))
+    }
+    pub fn bits(&mut self) -> f64 {
+        f64::from_bits(self.next_u64())
+    }
+}
+
+pub fn assert_close(actual: Complex, expected: Complex, rel_tol: f64) {
+    assert!(
+        actual.approx_eq(expected, rel_tol * f64::MIN_POSITIVE, rel_tol),
+        "{:?} is not within {:e} of {:?}", actual, rel_tol, expected,
+    );
+}
+
+pub fn assert_bits(actual: Complex, expected: Complex) {
+    assert!(
+        actual.real.to_bits() == expected.real.to_bits() && actual.imag.to_bits() == expected.imag.to_bits(),
+        "{:?} is not bitwise equal to {:?}", actual, expected,
+    );
+}
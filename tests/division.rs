@@ -0,0 +1,72 @@
+use complex_proto::{Complex, Complex32};
+
+mod common;
+use common::{assert_close, Rng};
+
+// Test cases from Baudin & Smith, "A Robust Complex Division in Scilab" (2012).
+type Case = ((f64, f64), (f64, f64), (f64, f64));
+
+const EXTREME: [Case; 10] = [
+    ((1.0, 1.0), (1.0, 1e307), (1e-307, -1e-307)),
+    ((1.0, 1.0), (1e-307, 1e-307), (1e307, 0.0)),
+    ((1e307, 1e-307), (1e204, 1e-204), (1e103, -1e-305)),
+    ((1e-10, 1e-10), (1e-300, 1e-300), (1e290, 0.0)),
+    ((1e300, 1e300), (1e300, 1e300), (1.0, 0.0)),
+    ((1e-300, 1e-300), (1e-300, 1e-300), (1.0, 0.0)),
+    ((1e308, 1e-308), (1e-308, 1e308), (0.0, -1.0)),
+    ((1e307, 1e-307), (1e-307, 1e307), (1e-307, -1.0)),
+    ((1.0, 1e308), (1e308, 1.0), (1e-308, 1.0)),
+    ((3.0, 4.0), (1.0, 2.0), (2.2, -0.4)),
+];
+
+#[test]
+fn extreme_magnitudes() {
+    for &((a, b), (c, d), (e, f)) in EXTREME.iter() {
+        let q: Complex = Complex::new(a, b) / Complex::new(c, d);
+        assert_close(q, Complex::new(e, f), 4.0 * f64::EPSILON);
+        assert!(q.real.is_finite() && q.imag.is_finite());
+    }
+}
+
+#[test]
+fn div_assign_matches_div() {
+    for &((a, b), (c, d), _) in EXTREME.iter() {
+        let mut z: Complex = Complex::new(a, b);
+        z /= Complex::new(c, d);
+        assert_eq!(z, Complex::new(a, b) / Complex::new(c, d));
+    }
+}
+
+#[test]
+fn matches_naive_formula_in_safe_range() {
+    let mut rng: Rng = Rng::new(3);
+    for _ in 0..10000 {
+        let (x, y): (Complex, Complex) = (rng.complex(-1e3, 1e3), rng.complex(-1e3, 1e3));
+        let denom: f64 = y.norm();
+        let naive: Complex = Complex::new(
+            (x.real * y.real + x.imag * y.imag) / denom,
+            (x.imag * y.real - x.real * y.imag) / denom,
+        );
+        assert!((x / y).approx_eq(naive, 1e-300, 1e-13));
+    }
+}
+
+#[test]
+fn single_precision() {
+    let q: Complex32 = Complex32::new(1e30, 1e30) / Complex32::new(1e30, 1e-30);
+    assert_eq!(q, Complex32::new(1.0, 1.0));
+    let q: Complex32 = Complex32::new(1.0, 1.0) / Complex32::new(1e-38, 1e-38);
+    assert!(q.real.is_finite() && q.imag == 0.0);
+}
+
+#[test]
+fn infinities_and_zero_divisor() {
+    let inf: f64 = f64::INFINITY;
+    let q: Complex = Complex::new(1.0, 2.0) / Complex::new(0.0, 0.0);
+    assert_eq!(q.real, inf);
+    assert_eq!(q.imag, inf);
+    let q: Complex = Complex::new(inf, 1.0) / Complex::new(2.0, 3.0);
+    assert_eq!((q.real, q.imag), (inf, -inf));
+    let q: Complex = Complex::new(1.0, 2.0) / Complex::new(inf, f64::NAN);
+    assert_eq!((q.real, q.imag), (0.0, 0.0));
+}
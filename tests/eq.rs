@@ -0,0 +1,57 @@
+use std::collections::{BTreeSet, HashSet};
+
+use complex_proto::{Complex, Complex32, GaussianInt, TotalComplex};
+
+#[test]
+fn partial_eq_follows_ieee() {
+    assert_eq!(Complex::new(1.0, -2.0), Complex::new(1.0, -2.0));
+    assert_eq!(Complex::new(0.0, 0.0), Complex::new(-0.0, -0.0));
+    assert_ne!(Complex::new(f64::NAN, 0.0), Complex::new(f64::NAN, 0.0));
+}
+
+#[test]
+fn total_complex_hashes_and_orders() {
+    let values: [Complex; 6] = [
+        Complex::new(1.0, 2.0),
+        Complex::new(1.0, 2.0),
+        Complex::new(f64::NAN, 0.0),
+        Complex::new(-f64::NAN, 0.0),
+        Complex::new(0.0, 0.0),
+        Complex::new(-0.0, 0.0),
+    ];
+    let hashed: HashSet<TotalComplex> = values.iter().map(|&z| z.into()).collect();
+    let ordered: BTreeSet<TotalComplex> = values.iter().map(|&z| z.into()).collect();
+    assert_eq!(hashed.len(), 4);
+    assert_eq!(ordered.len(), 4);
+    let first: Complex = ordered.iter().next().unwrap().0;
+    assert!(first.real == 0.0 && first.real.is_sign_negative());
+}
+
+#[test]
+fn gaussian_integers_are_eq_and_hash() {
+    let set: HashSet<GaussianInt> = [GaussianInt::new(1, 2), GaussianInt::new(1, 2), GaussianInt::new(2, 1)].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn approx_eq_tolerances() {
+    let a: Complex = Complex::new(1.0, 2.0);
+    let b: Complex = Complex::new(1.0 + 1e-15, 2.0);
+    assert!(a.approx_eq(b, 0.0, 1e-14));
+    assert!(!a.approx_eq(b, 0.0, 1e-17));
+    assert!(Complex::new(1e-20, 0.0).approx_eq(Complex::new(0.0, 0.0), 1e-18, 0.0));
+    let inf: Complex = Complex::new(f64::INFINITY, 0.0);
+    assert!(inf.approx_eq(inf, 0.0, 0.0));
+    assert!(!Complex::new(f64::NAN, 0.0).approx_eq(Complex::new(f64::NAN, 0.0), 1.0, 1.0));
+}
+
+#[test]
+fn ulp_distance() {
+    let a: Complex = Complex::new(1.0, 2.0);
+    assert_eq!(a.ulp_distance(a), 0);
+    assert_eq!(a.ulp_distance(Complex::new(1.0 + f64::EPSILON, 2.0)), 1);
+    assert_eq!(Complex::new(0.0, -0.0).ulp_distance(Complex::new(-0.0, 5e-324)), 1);
+    assert_eq!(a.ulp_distance(Complex::new(f64::NAN, 2.0)), u64::MAX);
+    let f: Complex32 = Complex32::new(1.0, 0.0);
+    assert_eq!(f.ulp_distance(Complex32::new(1.0 + 2.0 * f32::EPSILON, 0.0)), 2);
+}
@@ -0,0 +1,95 @@
+use std::f64::consts::PI;
+
+use complex_proto::fft::{self, Direction, FftPlan};
+use complex_proto::{Complex, Complex32};
+
+mod common;
+use common::Rng;
+
+const LENGTHS: [usize; 16] = [2, 3, 4, 5, 8, 12, 16, 30, 64, 97, 128, 202, 210, 289, 1000, 1024];
+
+fn dft(x: &[Complex], sign: f64) -> Vec<Complex> {
+    let n: usize = x.len();
+    (0..n)
+        .map(|k| {
+            x.iter()
+                .enumerate()
+                .map(|(j, &z)| z * Complex::from_polar(1.0, sign * 2.0 * PI * ((j * k) % n) as f64 / n as f64))
+                .sum()
+        })
+        .collect()
+}
+
+fn max_error(actual: &[Complex], expected: &[Complex]) -> f64 {
+    let scale: f64 = expected.iter().map(|z| z.abs()).fold(1.0, f64::max);
+    actual.iter().zip(expected).map(|(&a, &e)| (a - e).abs()).fold(0.0, f64::max) / scale
+}
+
+#[test]
+fn matches_naive_dft() {
+    let mut rng: Rng = Rng::new(20);
+    for n in (1..=40).chain(LENGTHS) {
+        let x: Vec<Complex> = (0..n).map(|_| rng.complex(-1.0, 1.0)).collect();
+        let plan: FftPlan = FftPlan::new(n);
+        let mut y: Vec<Complex> = x.clone();
+        plan.forward(&mut y);
+        let err: f64 = max_error(&y, &dft(&x, -1.0));
+        assert!(err < 1e-13, "forward n={} error {:e}", n, err);
+        let mut z: Vec<Complex> = x.clone();
+        plan.inverse(&mut z);
+        let expected: Vec<Complex> = dft(&x, 1.0).into_iter().map(|z| z / n as f64).collect();
+        let err: f64 = max_error(&z, &expected);
+        assert!(err < 1e-13, "inverse n={} error {:e}", n, err);
+    }
+}
+
+#[test]
+fn round_trip() {
+    let mut rng: Rng = Rng::new(2020);
+    for n in LENGTHS.into_iter().chain([4096, 4099, 6000]) {
+        let x: Vec<Complex> = (0..n).map(|_| rng.complex(-1.0, 1.0)).collect();
+        let plan: FftPlan = FftPlan::new(n);
+        let mut scratch: Vec<Complex> = vec![Complex::default(); plan.scratch_len()];
+        let mut y: Vec<Complex> = x.clone();
+        plan.process_with_scratch(&mut y, &mut scratch, Direction::Forward);
+        let energy: f64 = x.iter().map(|z| z.norm()).sum::<f64>();
+        assert!((y.iter().map(|z| z.norm()).sum::<f64>() / n as f64 - energy).abs() < 1e-11 * energy);
+        plan.process_with_scratch(&mut y, &mut scratch, Direction::Inverse);
+        let err: f64 = max_error(&y, &x);
+        assert!(err < 1e-13, "round trip n={} error {:e}", n, err);
+    }
+}
+
+#[test]
+fn impulse_and_constant() {
+    let mut x: Vec<Complex> = vec![Complex::default(); 12];
+    x[0] = Complex::new(1.0, 0.0);
+    fft::forward(&mut x);
+    assert!(x.iter().all(|&z| z == Complex::new(1.0, 0.0)));
+    fft::inverse(&mut x);
+    assert!(max_error(&x[1..], &[Complex::default(); 11]) < 1e-15);
+    assert!((x[0] - Complex::new(1.0, 0.0)).abs() < 1e-15);
+    let mut empty: Vec<Complex> = Vec::new();
+    fft::forward(&mut empty);
+    let mut one: [Complex; 1] = [Complex::new(2.0, -3.0)];
+    fft::inverse(&mut one);
+    assert_eq!(one, [Complex::new(2.0, -3.0)]);
+}
+
+#[test]
+fn single_precision() {
+    let x: Vec<Complex32> = (0..30).map(|i| Complex32::new(i as f32, 1.0 - i as f32 * 0.5)).collect();
+    let mut y: Vec<Complex32> = x.clone();
+    let plan: FftPlan<f32> = FftPlan::new(30);
+    plan.forward(&mut y);
+    plan.inverse(&mut y);
+    for (a, b) in y.iter().zip(&x) {
+        assert!((*a - *b).abs() < 1e-4, "{:?} != {:?}", a, b);
+    }
+}
+
+#[test]
+#[should_panic(expected = "does not match")]
+fn length_mismatch() {
+    FftPlan::<f64>::new(8).forward(&mut [Complex::default(); 4]);
+}
@@ -0,0 +1,141 @@
+use complex_proto::GaussianInt;
+
+#[test]
+fn euclidean_division() {
+    for x in -20..20 {
+        for y in -20..20 {
+            for u in -7..7 {
+                for v in -7..7 {
+                    let (a, b): (GaussianInt, GaussianInt) = (GaussianInt::new(x, y), GaussianInt::new(u, v));
+                    if b.is_zero() {
+                        continue;
+                    }
+                    let (q, r): (GaussianInt, GaussianInt) = a.div_rem(b);
+                    assert_eq!(q * b + r, a);
+                    assert!(2 * r.norm() <= b.norm());
+                    assert_eq!((a / b, a % b), (q, r));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn exact_arithmetic() {
+    let (a, b): (GaussianInt, GaussianInt) = (GaussianInt::new(3, 4), GaussianInt::new(-2, 5));
+    assert_eq!(a * b, GaussianInt::new(-26, 7));
+    assert_eq!(a.norm(), 25);
+    assert_eq!(a * a.conj(), GaussianInt::new(25, 0));
+    assert_eq!(-a + b - a, GaussianInt::new(-8, -3));
+}
+
+#[test]
+fn gcd() {
+    assert_eq!(GaussianInt::new(12, 0).gcd(GaussianInt::new(0, 18)), GaussianInt::new(6, 0));
+    let p: GaussianInt = GaussianInt::new(3, 1);
+    assert_eq!((p * GaussianInt::new(2, 5)).gcd(p * GaussianInt::new(7, 0)), p);
+    assert_eq!(GaussianInt::new(0, 0).gcd(GaussianInt::new(0, 0)), GaussianInt::new(0, 0));
+    assert_eq!(GaussianInt::new(0, -5).gcd(GaussianInt::new(0, 0)), GaussianInt::new(5, 0));
+}
+
+#[test]
+fn primality() {
+    let primes: Vec<GaussianInt> = (0..=3)
+        .flat_map(|x| (0..=3).map(move |y| GaussianInt::new(x, y)))
+        .filter(|z| z.is_prime())
+        .collect();
+    let expected: Vec<GaussianInt> = [(0, 3), (1, 1), (1, 2), (2, 1), (2, 3), (3, 0), (3, 2)]
+        .iter()
+        .map(|&(x, y)| GaussianInt::new(x, y))
+        .collect();
+    assert_eq!(primes, expected);
+    assert!(GaussianInt::new(1_000_000_007, 0).is_prime());
+    assert!(GaussianInt::new(0, -1_000_000_007).is_prime());
+    assert!(!GaussianInt::new(5, 0).is_prime());
+    assert!(!GaussianInt::new(1, 0).is_prime());
+}
+
+fn check_div_rem(a: GaussianInt, b: GaussianInt) {
+    let (q, r): (GaussianInt, GaussianInt) = a.div_rem(b);
+    let wide = |z: GaussianInt| -> (i128, i128) { (z.real as i128, z.imag as i128) };
+    let ((qr, qi), (br, bi), (rr, ri)): ((i128, i128), (i128, i128), (i128, i128)) = (wide(q), wide(b), wide(r));
+    assert_eq!((qr * br - qi * bi + rr, qr * bi + qi * br + ri), wide(a));
+    let norm = |(x, y): (i128, i128)| -> u128 { x.unsigned_abs().pow(2) + y.unsigned_abs().pow(2) };
+    assert!(2 * norm((rr, ri)) <= norm((br, bi)), "{:?} % {:?} = {:?}", a, b, r);
+}
+
+#[test]
+fn division_near_the_limits() {
+    let edges: [i64; 8] = [i64::MIN, i64::MIN + 1, -3_037_000_500, -1, 1, 3_037_000_500, i64::MAX - 1, i64::MAX];
+    for &x in edges.iter() {
+        for &y in edges.iter() {
+            for &(u, v) in [(i64::MAX, i64::MAX), (i64::MIN + 1, i64::MAX), (i64::MAX, 3), (7, -5)].iter() {
+                check_div_rem(GaussianInt::new(x, y), GaussianInt::new(u, v));
+            }
+        }
+    }
+    assert_eq!(GaussianInt::new(i64::MIN, i64::MIN).div_rem(GaussianInt::new(i64::MIN, i64::MIN)).0, GaussianInt::new(1, 0));
+    assert_eq!(GaussianInt::new(i64::MAX, 0) / GaussianInt::new(0, 1), GaussianInt::new(0, -i64::MAX));
+}
+
+#[test]
+#[should_panic(expected = "quotient out of range")]
+fn quotient_overflow_panics() {
+    let _ = GaussianInt::new(i64::MIN, 0) / GaussianInt::new(0, 1);
+}
+
+#[test]
+fn primality_near_the_limits() {
+    assert!(!GaussianInt::new(3_037_000_500, 1).is_prime());
+    assert!(GaussianInt::new(3_037_000_524, 1).is_prime());
+    assert!(GaussianInt::new(9_223_372_036_854_775_708, i64::MAX).is_prime());
+    assert!(GaussianInt::new(0, 9_223_372_036_854_775_783).is_prime());
+    assert!(!GaussianInt::new(i64::MAX, 0).is_prime());
+    assert!(!GaussianInt::new(i64::MAX, i64::MAX).is_prime());
+    assert!(!GaussianInt::new(i64::MIN, i64::MIN).is_prime());
+    let p: GaussianInt = GaussianInt::new(3_037_000_414, 1);
+    assert!(p.is_prime());
+    assert!(!(p * p).is_prime());
+    assert!(!GaussianInt::new(i64::MIN, i64::MIN).is_unit());
+    assert!(GaussianInt::new(0, -1).is_unit());
+}
+
+#[test]
+#[should_panic(expected = "remainder out of range")]
+fn remainder_overflow_panics() {
+    let _ = GaussianInt::new(i64::MIN, 0) % GaussianInt::new(i64::MIN, i64::MIN);
+}
+
+#[test]
+fn norm_near_the_limits() {
+    assert_eq!(GaussianInt::new(3, -4).norm_wide(), 25);
+    assert_eq!(GaussianInt::new(4_000_000_000, 0).norm_wide(), 16_000_000_000_000_000_000);
+    assert_eq!(GaussianInt::new(i64::MAX, -i64::MAX).norm_wide(), 2 * (i64::MAX as u128).pow(2));
+    assert_eq!(GaussianInt::new(i64::MIN, i64::MIN).norm_wide(), 1 << 127);
+    assert_eq!(GaussianInt::new(3_037_000_499, 1).norm() as u128, GaussianInt::new(3_037_000_499, 1).norm_wide());
+}
+
+#[test]
+fn gcd_near_the_limits() {
+    assert_eq!(GaussianInt::new(i64::MIN, 0).gcd(GaussianInt::new(0, 1)), GaussianInt::new(1, 0));
+    assert_eq!(GaussianInt::new(0, 1).gcd(GaussianInt::new(i64::MIN, 0)), GaussianInt::new(1, 0));
+    assert_eq!(GaussianInt::new(i64::MIN, 0).gcd(GaussianInt::new(0, i64::MIN + 2)), GaussianInt::new(2, 0));
+    assert_eq!(GaussianInt::new(i64::MIN, i64::MIN).gcd(GaussianInt::new(i64::MAX, i64::MAX)), GaussianInt::new(1, 1));
+    let p: GaussianInt = GaussianInt::new(3_037_000_414, 1);
+    assert_eq!((p * GaussianInt::new(2, 1)).gcd(p * GaussianInt::new(1, -3)), p);
+    assert_eq!((p * GaussianInt::new(-1, 2)).gcd(GaussianInt::new(i64::MAX, 7)), GaussianInt::new(1, 0));
+    assert_eq!(GaussianInt::new(i64::MIN + 1, 0).normalize(), GaussianInt::new(i64::MAX, 0));
+    assert_eq!(GaussianInt::new(0, i64::MIN + 1).normalize(), GaussianInt::new(i64::MAX, 0));
+}
+
+#[test]
+#[should_panic(expected = "gcd: result out of range")]
+fn unrepresentable_gcd_panics() {
+    let _ = GaussianInt::new(i64::MIN, 0).gcd(GaussianInt::new(0, i64::MIN));
+}
+
+#[test]
+#[should_panic(expected = "normalize: component out of range")]
+fn unrepresentable_normalize_panics() {
+    let _ = GaussianInt::new(0, i64::MIN).normalize();
+}
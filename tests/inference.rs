@@ -0,0 +1,26 @@
+use complex_proto::{Complex, Complex32, ComplexT, Float, GaussianInt};
+
+// Call sites as written before `Complex` became generic, with nothing pinning the scalar type.
+#[test]
+fn unannotated_call_sites_are_f64() {
+    let a = Complex::REAL_UNIT;
+    let b = Complex::new(1.0, 2.0);
+    let c = a + b * Complex::IMAG_UNIT;
+    assert_eq!(c, Complex::new(-1.0, 1.0));
+    assert_eq!(Complex::new(3.0, 4.0).abs(), 5.0);
+    assert_eq!(Complex::with_real(-4.0).sqrt(), Complex::with_imag(2.0));
+    assert_eq!(Complex::default().norm(), 0.0);
+    let r: f64 = Complex::from_polar(2.0, 0.0).real;
+    assert_eq!(r, 2.0);
+}
+
+#[test]
+fn generic_code_uses_complex_t() {
+    fn norm<T: Float>(z: ComplexT<T>) -> T {
+        z.norm()
+    }
+    assert_eq!(norm(Complex::new(3.0, 4.0)), 25.0);
+    assert_eq!(norm(Complex32::new(3.0, 4.0)), 25.0f32);
+    let g: ComplexT<i64> = GaussianInt::new(3, 4);
+    assert_eq!(g.norm(), 25);
+}
@@ -0,0 +1,53 @@
+use std::mem::{align_of, size_of};
+
+use complex_proto::{Complex, Complex32, GaussianInt};
+
+#[test]
+fn layout() {
+    assert_eq!(size_of::<Complex>(), size_of::<[f64; 2]>());
+    assert_eq!(align_of::<Complex>(), align_of::<f64>());
+    assert_eq!(size_of::<Complex32>(), size_of::<[f32; 2]>());
+    assert_eq!(align_of::<Complex32>(), align_of::<f32>());
+}
+
+#[test]
+fn casts() {
+    let mut zs: Vec<Complex> = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+    assert_eq!(Complex::as_interleaved(&zs), &[1.0, 2.0, 3.0, 4.0]);
+    Complex::as_interleaved_mut(&mut zs)[3] = -4.0;
+    assert_eq!(zs[1], Complex::new(3.0, -4.0));
+    assert!(Complex::as_interleaved(&zs[..0]).is_empty());
+
+    let mut buf: Vec<f64> = vec![1.0, -1.0, 0.5, 0.25, 8.0, 9.0];
+    assert_eq!(Complex::from_interleaved(&buf).unwrap(), &zs_of(&[(1.0, -1.0), (0.5, 0.25), (8.0, 9.0)]));
+    Complex::from_interleaved_mut(&mut buf).unwrap()[0] *= Complex::IMAG_UNIT;
+    assert_eq!(&buf[..2], &[1.0, 1.0]);
+    assert_eq!(Complex::from_interleaved(&buf[..5]), None);
+    assert!(Complex::from_interleaved_mut(&mut buf[1..]).is_none());
+
+    let gs: [GaussianInt; 1] = [GaussianInt::new(5, -7)];
+    assert_eq!(GaussianInt::as_interleaved(&gs), &[5, -7]);
+}
+
+#[test]
+fn unaligned_subslice() {
+    let buf: Vec<f64> = (0..8).map(f64::from).collect();
+    let zs: &[Complex] = Complex::from_interleaved(&buf[1..7]).unwrap();
+    assert_eq!(zs, &zs_of(&[(1.0, 2.0), (3.0, 4.0), (5.0, 6.0)]));
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn pod() {
+    let zs: [Complex32; 2] = [Complex32::new(1.0, 2.0), Complex32::new(3.0, 4.0)];
+    let floats: &[f32] = bytemuck::cast_slice(&zs);
+    assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0]);
+    let bytes: &[u8] = bytemuck::cast_slice(&zs);
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(bytemuck::cast_slice::<u8, Complex32>(bytes), &zs);
+    assert_eq!(<Complex32 as bytemuck::Zeroable>::zeroed(), Complex32::new(0.0, 0.0));
+}
+
+fn zs_of(pairs: &[(f64, f64)]) -> Vec<Complex> {
+    pairs.iter().map(|&(real, imag)| Complex::new(real, imag)).collect()
+}
@@ -0,0 +1,77 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use complex_proto::{Complex, Complex32};
+
+mod common;
+use common::{assert_bits, assert_close, Rng};
+
+const ACOSH_2: f64 = 1.3169578969248166;
+const ATANH_HALF: f64 = 0.5493061443340549;
+
+type Case = (fn(Complex) -> Complex, Complex, Complex);
+
+#[test]
+fn branch_cuts_follow_signed_zero() {
+    let cases: [Case; 12] = [
+        (Complex::asin, Complex::new(2.0, 0.0), Complex::new(FRAC_PI_2, ACOSH_2)),
+        (Complex::asin, Complex::new(2.0, -0.0), Complex::new(FRAC_PI_2, -ACOSH_2)),
+        (Complex::acos, Complex::new(2.0, 0.0), Complex::new(0.0, -ACOSH_2)),
+        (Complex::acos, Complex::new(-2.0, -0.0), Complex::new(PI, ACOSH_2)),
+        (Complex::atan, Complex::new(0.0, 2.0), Complex::new(FRAC_PI_2, ATANH_HALF)),
+        (Complex::atan, Complex::new(-0.0, 2.0), Complex::new(-FRAC_PI_2, ATANH_HALF)),
+        (Complex::asinh, Complex::new(0.0, 2.0), Complex::new(ACOSH_2, FRAC_PI_2)),
+        (Complex::asinh, Complex::new(-0.0, 2.0), Complex::new(-ACOSH_2, FRAC_PI_2)),
+        (Complex::acosh, Complex::new(-2.0, 0.0), Complex::new(ACOSH_2, PI)),
+        (Complex::acosh, Complex::new(-2.0, -0.0), Complex::new(ACOSH_2, -PI)),
+        (Complex::atanh, Complex::new(2.0, 0.0), Complex::new(ATANH_HALF, FRAC_PI_2)),
+        (Complex::atanh, Complex::new(2.0, -0.0), Complex::new(ATANH_HALF, -FRAC_PI_2)),
+    ];
+    for (f, z, expected) in cases {
+        assert_close(f(z), expected, 2.0 * f64::EPSILON);
+    }
+}
+
+#[test]
+fn real_axis_inside_the_cuts() {
+    assert_bits(Complex::new(0.5, 0.0).asin(), Complex::new(0.5f64.asin(), 0.0));
+    assert_bits(Complex::new(0.5, -0.0).acos(), Complex::new(0.5f64.acos(), 0.0));
+    assert_bits(Complex::new(0.5, 0.0).atanh(), Complex::new(0.5f64.atanh(), 0.0));
+    assert_close(Complex::new(3.0, 0.0).acosh(), Complex::new(3f64.acosh(), 0.0), 2.0 * f64::EPSILON);
+    assert_close(Complex::new(-3.0, 0.0).asinh(), Complex::new(-(3f64.asinh()), 0.0), 2.0 * f64::EPSILON);
+    assert_bits(Complex::new(1e-300, 0.0).asin(), Complex::new(1e-300, 0.0));
+}
+
+#[test]
+fn no_overflow_for_huge_arguments() {
+    for z in [Complex::new(1e300, 1e300), Complex::new(-1.7e308, 1.0), Complex::new(3.0, -1e160)] {
+        for (name, w) in [("asin", z.asin()), ("acos", z.acos()), ("asinh", z.asinh()), ("acosh", z.acosh()), ("atanh", z.atanh())] {
+            assert!(w.is_finite(), "{}({:?}) = {:?}", name, z, w);
+        }
+    }
+    assert_close(Complex::new(1e300, 0.0).acosh(), Complex::new(1e300f64.ln() + 2f64.ln(), 0.0), f64::EPSILON);
+}
+
+#[test]
+fn inverses_round_trip() {
+    let mut rng: Rng = Rng::new(22);
+    for _ in 0..2000 {
+        let z: Complex = rng.complex(-3.0, 3.0);
+        assert_close(z.asin().sin(), z, 1e-14);
+        assert_close(z.acos().cos(), z, 1e-14);
+        assert_close(z.atan().tan(), z, 1e-14);
+        assert_close(z.asinh().sinh(), z, 1e-14);
+        assert_close(z.acosh().cosh(), z, 1e-14);
+        assert_close(z.atanh().tanh(), z, 1e-14);
+        assert_close(z.asin() + z.acos(), Complex::new(FRAC_PI_2, 0.0), 1e-14);
+    }
+}
+
+#[test]
+fn single_precision() {
+    let z: Complex32 = Complex32::new(0.75, -1e-6);
+    let w: Complex = Complex::new(0.75, -1e-6);
+    for (a, b) in [(z.asin(), w.asin()), (z.acos(), w.acos()), (z.atanh(), w.atanh()), (z.acosh(), w.acosh())] {
+        assert!((a.real as f64 - b.real).abs() <= 2.0 * f32::EPSILON as f64 * b.real.abs(), "{:?} vs {:?}", a, b);
+        assert!((a.imag as f64 - b.imag).abs() <= 2.0 * f32::EPSILON as f64 * b.imag.abs(), "{:?} vs {:?}", a, b);
+    }
+}
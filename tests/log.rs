@@ -0,0 +1,71 @@
+use std::f64::consts::{FRAC_PI_2, LN_10, LN_2, PI};
+
+use complex_proto::{Complex, Complex32};
+
+mod common;
+use common::{assert_close, Rng};
+
+#[test]
+fn known_values() {
+    assert_close(Complex::new(-100.0, 0.0).log10(), Complex::new(2.0, PI / LN_10), 1e-15);
+    assert_close(Complex::new(0.0, 8.0).log2(), Complex::new(3.0, FRAC_PI_2 / LN_2), 1e-15);
+    assert_close(Complex::new(-9.0, 0.0).log(3.0), Complex::new(2.0, PI / 3f64.ln()), 1e-15);
+    assert_eq!(Complex::new(0.0, 0.0).log2(), Complex::new(f64::NEG_INFINITY, 0.0));
+    assert_eq!(Complex::new(1024.0, 0.0).log2(), Complex::new(10.0, 0.0));
+    assert_eq!(Complex::new(-0.0, -0.0).log10().imag, -PI * std::f64::consts::LOG10_E);
+}
+
+#[test]
+fn identities_with_ln() {
+    let mut rng: Rng = Rng::new(21);
+    for _ in 0..1000 {
+        let z: Complex = rng.complex(-50.0, 50.0);
+        let ln: Complex = z.ln();
+        assert_close(z.log2() * LN_2, ln, 1e-15);
+        assert_close(z.log10() * LN_10, ln, 1e-15);
+        let base: f64 = rng.uniform(0.1, 20.0);
+        assert_close(z.log(base) * base.ln(), ln, 1e-14);
+        assert_close(Complex::with_real(2.0).pow(z.log2()), z, 1e-13);
+    }
+}
+
+#[test]
+fn complex_base() {
+    let mut rng: Rng = Rng::new(2121);
+    for _ in 0..1000 {
+        let (z, w): (Complex, Complex) = (rng.complex(-50.0, 50.0), rng.complex(-5.0, 5.0));
+        assert_close(z.log_complex(w) * w.ln(), z.ln(), 1e-13);
+        assert_close(w.pow(z.log_complex(w)), z, 1e-11);
+    }
+    assert_close(Complex::new(-8.0, 0.0).log(-2.0), Complex::new(-8.0, 0.0).ln() / Complex::new(-2.0, 0.0).ln(), 1e-15);
+    assert_close(Complex::new(5.0, -1.0).log(7.0), Complex::new(5.0, -1.0).log_complex(Complex::new(7.0, 0.0)), 1e-15);
+}
+
+#[test]
+fn ln_1p_near_minus_one() {
+    for &(u, y) in [(0.0, 1e-10), (0.0, -1e-7), (2f64.powi(-30), 1e-7), (2f64.powi(-40), -3e-300), (0.25, 1e-9)].iter() {
+        let expected: Complex = Complex::new(0.5 * (u * u + y * y).ln(), y.atan2(u));
+        assert_close(Complex::new(u - 1.0, y).ln_1p(), expected, 1e-15);
+    }
+    assert_close(Complex::new(-1.0, 1e-10).ln_1p(), Complex::new(-23.025850929940457, FRAC_PI_2), 1e-15);
+    for &(u, y) in [(0.0f32, 1.5e-5f32), (0.0, -1e-20), (2f32.powi(-20), 1e-6), (0.125, -1e-4)].iter() {
+        let z: Complex32 = Complex32::new(u - 1.0, y).ln_1p();
+        let (u, y): (f64, f64) = (u as f64, y as f64);
+        let (re, im): (f64, f64) = (0.5 * (u * u + y * y).ln(), y.atan2(u));
+        assert!((z.real as f64 - re).abs() <= 4.0 * f32::EPSILON as f64 * re.abs(), "{:?} vs {}", z, re);
+        assert!((z.imag as f64 - im).abs() <= 4.0 * f32::EPSILON as f64 * im.abs(), "{:?} vs {}", z, im);
+    }
+}
+
+#[test]
+fn logs_near_the_unit_circle() {
+    let x: f64 = -5.14e-11;
+    let z: Complex = Complex::new(x, -1.0);
+    let ln_abs: f64 = 0.5 * x * x;
+    let cases: [(Complex, f64); 3] = [(z.log2(), LN_2), (z.log10(), LN_10), (z.log(7.0), 7f64.ln())];
+    for &(w, ln_base) in cases.iter() {
+        let expected: Complex = Complex::new(ln_abs / ln_base, z.arg() / ln_base);
+        assert!((w.real - expected.real).abs() <= 4.0 * f64::EPSILON * expected.real, "{:?} vs {:?}", w, expected);
+        assert_close(w, expected, 1e-15);
+    }
+}
@@ -0,0 +1,110 @@
+use complex_proto::{Complex, Complex32};
+
+mod common;
+use common::Rng;
+
+const TWO_52: i128 = 1 << 52;
+
+// Integer-valued inputs keep every intermediate an integer, so the exact products fit in i128.
+fn integer(rng: &mut Rng) -> i128 {
+    TWO_52 + (rng.next_u64() >> 12) as i128
+}
+
+fn assert_component(actual: f64, exact: i128, what: &str) {
+    let err: f64 = (actual as i128 - exact).unsigned_abs() as f64;
+    assert!(
+        err <= 2.0 * f64::EPSILON * (exact as f64).abs(),
+        "{}: {:e} is {:e} away from {}", what, actual, err, exact,
+    );
+}
+
+fn check(a: [i128; 2], b: [i128; 2], scale: f64) {
+    let z: Complex = Complex::new(a[0] as f64, a[1] as f64) * scale;
+    let w: Complex = Complex::new(b[0] as f64, b[1] as f64);
+    let product: Complex = z.mul_accurate(w) * scale.recip();
+    assert_component(product.real, a[0] * b[0] - a[1] * b[1], "real");
+    assert_component(product.imag, a[0] * b[1] + a[1] * b[0], "imag");
+}
+
+#[test]
+fn cancelling_products_are_exact() {
+    let z: Complex = Complex::new(1.0 + 2f64.powi(-30), 1.0);
+    let w: Complex = Complex::new(1.0 - 2f64.powi(-30), 1.0);
+    assert_eq!(z.mul_accurate(w), Complex::new(-2f64.powi(-60), 2.0));
+    assert_eq!(w.mul_accurate(z.conj()), Complex::new(2.0, 2f64.powi(-29)));
+}
+
+#[test]
+fn real_part_cancellation() {
+    let mut rng: Rng = Rng::new(25);
+    for i in 0..10000 {
+        let (ar, ai, bi): (i128, i128, i128) = (integer(&mut rng), integer(&mut rng), integer(&mut rng));
+        let br: i128 = (ai * bi + ar / 2) / ar + (rng.next_u64() % 3) as i128 - 1;
+        if br >= 2 * TWO_52 {
+            continue;
+        }
+        check([ar, ai], [br, bi], 2f64.powi(i % 200 - 100));
+    }
+}
+
+#[test]
+fn imag_part_cancellation() {
+    let mut rng: Rng = Rng::new(26);
+    for i in 0..10000 {
+        let (ar, ai, br): (i128, i128, i128) = (integer(&mut rng), integer(&mut rng), integer(&mut rng));
+        let bi: i128 = -((ai * br + ar / 2) / ar) + (rng.next_u64() % 3) as i128 - 1;
+        if bi <= -2 * TWO_52 {
+            continue;
+        }
+        check([ar, ai], [br, bi], 2f64.powi(i % 200 - 100));
+    }
+}
+
+#[test]
+fn single_precision() {
+    let mut rng: Rng = Rng::new(27);
+    for _ in 0..10000 {
+        let (ar, ai, bi): (f32, f32, f32) =
+            (rng.uniform(0.5, 2.0) as f32, rng.uniform(0.5, 2.0) as f32, rng.uniform(-2.0, 2.0) as f32);
+        let br: f32 = (ai as f64 * bi as f64 / ar as f64) as f32;
+        let product: Complex32 = Complex32::new(ar, ai).mul_accurate(Complex32::new(br, bi));
+        let real: f64 = (ar as f64).mul_add(br as f64, -(ai as f64 * bi as f64));
+        let imag: f64 = (ai as f64).mul_add(br as f64, ar as f64 * bi as f64);
+        assert!((product.real as f64 - real).abs() <= 2.0 * f32::EPSILON as f64 * real.abs(), "{} vs {}", product.real, real);
+        assert!((product.imag as f64 - imag).abs() <= 2.0 * f32::EPSILON as f64 * imag.abs(), "{} vs {}", product.imag, imag);
+    }
+}
+
+#[test]
+fn plain_product_is_normwise_accurate() {
+    let mut rng: Rng = Rng::new(28);
+    for _ in 0..1000 {
+        let (z, w): (Complex, Complex) = (rng.complex(0.0, 10.0), rng.complex(0.0, 10.0));
+        let exact: Complex = z.mul_accurate(w);
+        let mut plain: Complex = z;
+        plain *= w;
+        assert!((plain - exact).abs() <= 4.0 * f64::EPSILON * exact.abs());
+    }
+}
+
+#[cfg(feature = "accurate-mul")]
+#[test]
+fn operators_use_the_accurate_product() {
+    let mut rng: Rng = Rng::new(29);
+    for _ in 0..1000 {
+        let (z, w): (Complex, Complex) = (rng.complex(-10.0, 10.0), rng.complex(-10.0, 10.0));
+        common::assert_bits(z * w, z.mul_accurate(w));
+        let mut assigned: Complex = z;
+        assigned *= w;
+        common::assert_bits(assigned, z.mul_accurate(w));
+    }
+    let z: Complex = Complex::new(1.0 + 2f64.powi(-30), 1.0);
+    assert_eq!(z * Complex::new(1.0 - 2f64.powi(-30), 1.0), Complex::new(-2f64.powi(-60), 2.0));
+}
+
+#[cfg(not(feature = "accurate-mul"))]
+#[test]
+fn operators_round_each_product() {
+    let z: Complex = Complex::new(1.0 + 2f64.powi(-30), 1.0);
+    assert_eq!(z * Complex::new(1.0 - 2f64.powi(-30), 1.0), Complex::new(0.0, 2.0));
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "num")]
+
+use num_traits::{Inv, Num, One, Pow, Zero};
+
+use complex_proto::{Complex, GaussianInt, ParseComplexError};
+
+mod common;
+use common::assert_close;
+
+fn horner<N: Num + Copy>(coeffs: &[N], x: N) -> N {
+    coeffs.iter().fold(N::zero(), |acc, &c| acc * x + c)
+}
+
+fn cube<N: Pow<i32, Output = N>>(x: N) -> N {
+    x.pow(3)
+}
+
+#[test]
+fn num_complex_roundtrip() {
+    let z: Complex = Complex::new(1.5, -2.5);
+    let w: num_complex::Complex64 = z.into();
+    assert_eq!(w, num_complex::Complex64::new(1.5, -2.5));
+    assert_eq!(Complex::from(w), z);
+    assert_eq!(Complex::from(w * w), z * z);
+    let g: num_complex::Complex<i64> = GaussianInt::new(3, -4).into();
+    assert_eq!(GaussianInt::from(g), GaussianInt::new(3, -4));
+}
+
+#[test]
+fn generic_numeric_code() {
+    let x: Complex = Complex::new(0.5, 2.0);
+    let coeffs: [Complex; 3] = [Complex::new(1.0, 0.0), Complex::new(0.0, -3.0), Complex::new(2.0, 1.0)];
+    assert_close(horner(&coeffs, x), x * x - Complex::IMAG_UNIT * 3.0 * x + Complex::new(2.0, 1.0), 1e-15);
+    assert_close(cube(x), x * x * x, 1e-15);
+    assert!(Complex::zero().is_zero());
+    assert!(Complex::one().is_one());
+    assert!(!Complex::IMAG_UNIT.is_one());
+    assert_eq!(Complex::new(0.0, 2.0).inv(), Complex::new(0.0, -0.5));
+    assert_eq!((&Complex::new(4.0, 0.0)).inv(), Complex::new(0.25, 0.0));
+    assert_eq!(Pow::pow(x, 2.0), x.powf(2.0));
+    assert_eq!(Pow::pow(x, x), x.pow(x));
+}
+
+#[test]
+fn from_str_radix() {
+    assert_eq!(Complex::from_str_radix("1.5-2i", 10), Ok(Complex::new(1.5, -2.0)));
+    assert_eq!(Complex::from_str_radix("101+11i", 2), Ok(Complex::new(5.0, 3.0)));
+    assert_eq!(Complex::from_str_radix("(ff, -10)", 16), Ok(Complex::new(255.0, -16.0)));
+    assert_eq!(Complex::from_str_radix("12i", 2), Err(ParseComplexError::InvalidImag));
+}
+
+#[test]
+fn remainder() {
+    assert_eq!(Complex::new(5.0, 3.0) % Complex::new(2.0, 0.0), Complex::new(1.0, 1.0));
+    assert_eq!(Complex::new(-5.0, 3.0) % Complex::new(2.0, 0.0), Complex::new(-1.0, 1.0));
+    let mut z: Complex = Complex::new(7.0, 1.0);
+    z %= Complex::new(0.0, 3.0);
+    assert_eq!(z, Complex::new(1.0, 1.0));
+}
+
+#[test]
+fn remainder_truncates_toward_zero() {
+    assert_eq!(Complex::new(-7.0, -5.0) % Complex::new(-2.0, 0.0), Complex::new(-1.0, -1.0));
+    assert_eq!(Complex::new(-7.0, 5.0) % Complex::new(2.0, 0.0), Complex::new(-1.0, 1.0));
+    // (-7 + 3i) / (-2 - i) = 2.2 - 2.6i: truncated to 2 - 2i, where floor or rounding would give 2 - 3i
+    assert_eq!(Complex::new(-7.0, 3.0) % Complex::new(-2.0, -1.0), Complex::new(-1.0, 1.0));
+}
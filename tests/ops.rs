@@ -0,0 +1,68 @@
+#![allow(clippy::op_ref)]
+
+use complex_proto::{CompensatedSum, Complex, Complex32, GaussianInt};
+
+#[test]
+fn scalar_on_the_left() {
+    let z: Complex = Complex::new(3.0, 4.0);
+    assert_eq!(1.0 - z, Complex::new(-2.0, -4.0));
+    assert_eq!(2.0 + z, Complex::new(5.0, 4.0));
+    assert_eq!(2.0 * z, Complex::new(6.0, 8.0));
+    assert_eq!(1.0 / z, Complex::new(0.12, -0.16));
+    assert_eq!(2 * z, 2.0 * z);
+    assert_eq!(1 / z, 1.0 / z);
+    assert_eq!(2.0f32 - Complex32::new(1.0, 1.0), Complex32::new(1.0, -1.0));
+    assert_eq!(10 / GaussianInt::new(1, 2), GaussianInt::new(2, -4));
+    assert_eq!(5 - GaussianInt::new(3, 4), GaussianInt::new(2, -4));
+}
+
+#[test]
+fn scaled_reciprocal() {
+    let w: Complex = 1.0 / Complex::new(1e300, 1e300);
+    assert_eq!(w, Complex::new(5e-301, -5e-301));
+    assert_eq!(Complex::new(1e300, 1e300).recip(), w);
+}
+
+#[test]
+fn reference_operands() {
+    let (a, b): (Complex, Complex) = (Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5));
+    assert_eq!(&a + &b, a + b);
+    assert_eq!(&a - b, a - b);
+    assert_eq!(a * &b, a * b);
+    assert_eq!(&a / &b, a / b);
+    assert_eq!(&a * &2.0, a * 2.0);
+    assert_eq!(-&a, -a);
+    let mut c: Complex = a;
+    c += &b;
+    c /= &2.0;
+    assert_eq!(c, (a + b) / 2.0);
+    let g: GaussianInt = GaussianInt::new(5, 5);
+    assert_eq!(&g % &GaussianInt::new(2, 0), g % GaussianInt::new(2, 0));
+}
+
+#[test]
+fn sum_and_product() {
+    let v: Vec<Complex> = (1..=4).map(|k| Complex::new(k as f64, -(k as f64))).collect();
+    assert_eq!(v.iter().sum::<Complex>(), Complex::new(10.0, -10.0));
+    assert_eq!(v.iter().copied().sum::<Complex>(), Complex::new(10.0, -10.0));
+    assert_eq!(v.iter().product::<Complex>(), Complex::new(-96.0, 0.0));
+    assert_eq!(v.into_iter().product::<Complex>(), Complex::new(-96.0, 0.0));
+    let empty: [GaussianInt; 0] = [];
+    assert_eq!(empty.iter().product::<GaussianInt>(), GaussianInt::new(1, 0));
+}
+
+#[test]
+fn compensated_sum() {
+    let mut values: Vec<Complex> = vec![Complex::new(1e16, 1.0)];
+    values.extend(std::iter::repeat(Complex::new(1.0, 1e-16)).take(1000));
+    values.push(Complex::new(-1e16, -1.0));
+    assert_eq!(values.iter().sum::<Complex>(), Complex::new(0.0, 0.0));
+    let total: Complex = Complex::sum_compensated(&values);
+    assert_eq!(total.real, 1000.0);
+    assert!((total.imag - 1e-13).abs() < 1e-25);
+    let mut acc: CompensatedSum = CompensatedSum::new();
+    for z in values {
+        acc += z;
+    }
+    assert_eq!(acc.total(), total);
+}
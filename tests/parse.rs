@@ -0,0 +1,69 @@
+use complex_proto::{Complex, GaussianInt, ParseComplexError};
+
+mod common;
+use common::{assert_bits, Rng};
+
+#[test]
+fn accepted_forms() {
+    let cases: [(&str, Complex); 16] = [
+        ("3+4i", Complex::new(3.0, 4.0)),
+        ("1.5-2e-3i", Complex::new(1.5, -2e-3)),
+        ("3", Complex::new(3.0, 0.0)),
+        ("-2i", Complex::new(0.0, -2.0)),
+        ("i", Complex::new(0.0, 1.0)),
+        ("-i", Complex::new(0.0, -1.0)),
+        ("4j", Complex::new(0.0, 4.0)),
+        ("2-j", Complex::new(2.0, -1.0)),
+        ("(1,2)", Complex::new(1.0, 2.0)),
+        ("( -1.5 , 2e3 )", Complex::new(-1.5, 2e3)),
+        (" 3 + 4i ", Complex::new(3.0, 4.0)),
+        ("-1e-5+2e+5i", Complex::new(-1e-5, 2e5)),
+        ("inf-infi", Complex::new(f64::INFINITY, f64::NEG_INFINITY)),
+        ("-0-0i", Complex::new(-0.0, -0.0)),
+        ("+infi", Complex::new(0.0, f64::INFINITY)),
+        ("1e5i", Complex::new(0.0, 1e5)),
+    ];
+    for (s, expected) in cases {
+        assert_bits(s.parse::<Complex>().unwrap(), expected);
+    }
+}
+
+#[test]
+fn nan_components() {
+    for s in ["NaN+1i", "1NaNi", "NaNNaNi", "(nan, 1)"] {
+        assert!(s.parse::<Complex>().unwrap().is_nan(), "{}", s);
+    }
+}
+
+#[test]
+fn rejected_forms() {
+    assert_eq!("".parse::<Complex>(), Err(ParseComplexError::Empty));
+    assert_eq!("abc".parse::<Complex>(), Err(ParseComplexError::InvalidReal));
+    assert_eq!("3+4".parse::<Complex>(), Err(ParseComplexError::InvalidReal));
+    assert_eq!("1+xi".parse::<Complex>(), Err(ParseComplexError::InvalidImag));
+    assert_eq!("(1;2)".parse::<Complex>(), Err(ParseComplexError::InvalidPair));
+}
+
+#[test]
+fn gaussian_integers() {
+    assert_eq!("5-7i".parse::<GaussianInt>(), Ok(GaussianInt::new(5, -7)));
+    assert_eq!("-i".parse::<GaussianInt>(), Ok(GaussianInt::new(0, -1)));
+    assert_eq!("5.5-7i".parse::<GaussianInt>(), Err(ParseComplexError::InvalidReal));
+}
+
+#[test]
+fn display_round_trips_bit_for_bit() {
+    let mut rng: Rng = Rng::new(9);
+    for _ in 0..100000 {
+        let z: Complex = Complex::new(rng.bits(), rng.bits());
+        let back: Complex = z.to_string().parse().unwrap();
+        if z.is_nan() {
+            assert_eq!((back.real.is_nan(), back.imag.is_nan()), (z.real.is_nan(), z.imag.is_nan()));
+        } else {
+            assert_bits(back, z);
+        }
+    }
+    for z in [Complex::new(f64::INFINITY, -0.0), Complex::new(-0.0, f64::NEG_INFINITY), Complex::new(0.0, 0.0)] {
+        assert_bits(z.to_string().parse().unwrap(), z);
+    }
+}
@@ -0,0 +1,44 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use complex_proto::{Complex, Polar};
+
+mod common;
+use common::{assert_close, Rng};
+
+#[test]
+fn conversions() {
+    assert_close(Complex::from_polar(2.0, FRAC_PI_2), Complex::new(0.0, 2.0), 1e-15);
+    assert_eq!(Polar::from(Complex::new(-1.0, 0.0)), Polar::new(1.0, PI));
+    assert_eq!(Complex::new(3.0, 4.0).to_polar().r, 5.0);
+    let mut rng: Rng = Rng::new(13);
+    for _ in 0..1000 {
+        let z: Complex = rng.complex(-100.0, 100.0);
+        assert_close(Complex::from(Polar::from(z)), z, 1e-13);
+    }
+}
+
+#[test]
+fn angle_normalization() {
+    assert_eq!(Polar::<f64>::normalize_angle(-PI), PI);
+    assert_eq!(Polar::<f64>::normalize_angle(PI), PI);
+    assert!((Polar::<f64>::normalize_angle(7.0 * PI) - PI).abs() < 1e-14);
+    assert!((Polar::<f64>::normalize_angle(-2.5 * PI) + FRAC_PI_2).abs() < 1e-14);
+    assert_eq!(Polar::new(-1.0, 0.0).normalize(), Polar::new(1.0, PI));
+}
+
+#[test]
+fn arithmetic_matches_rectangular() {
+    let mut rng: Rng = Rng::new(17);
+    for _ in 0..1000 {
+        let (a, b): (Complex, Complex) = (rng.complex(-10.0, 10.0), rng.complex(-10.0, 10.0));
+        let (p, q): (Polar, Polar) = (a.to_polar(), b.to_polar());
+        assert_close((p * q).to_complex(), a * b, 1e-12);
+        assert_close((p / q).to_complex(), a / b, 1e-12);
+        assert_close(p.powi(3).to_complex(), a.powi(3), 1e-12);
+        assert_close(p.powf(0.5).to_complex(), a.sqrt(), 1e-12);
+        assert_close(p.sqrt().to_complex(), a.sqrt(), 1e-12);
+        assert_close(p.recip().to_complex(), a.recip(), 1e-12);
+        let theta: f64 = (p * q).theta;
+        assert!(-PI < theta && theta <= PI);
+    }
+}
@@ -0,0 +1,56 @@
+use complex_proto::Complex;
+
+mod common;
+use common::{assert_close, Rng};
+
+#[test]
+fn imaginary_unit_squared() {
+    let i: Complex = Complex::IMAG_UNIT;
+    assert_close(i.pow(Complex::with_real(2.0)), Complex::with_real(-1.0), 1e-15);
+    assert_close(i.pow(i), Complex::with_real((-std::f64::consts::FRAC_PI_2).exp()), 1e-15);
+}
+
+#[test]
+fn pow_matches_powf_for_real_exponents() {
+    let mut rng: Rng = Rng::new(7);
+    for _ in 0..10000 {
+        let z: Complex = rng.complex(-10.0, 10.0);
+        let exp: f64 = rng.uniform(-4.0, 4.0);
+        assert_close(z.pow(Complex::with_real(exp)), z.powf(exp), 1e-12);
+    }
+}
+
+#[test]
+fn pow_matches_powi_for_integer_exponents() {
+    let mut rng: Rng = Rng::new(11);
+    for _ in 0..10000 {
+        let z: Complex = rng.complex(-3.0, 3.0);
+        let exp: i32 = (rng.next_u64() % 17) as i32 - 8;
+        assert_close(z.pow(Complex::with_real(exp as f64)), z.powi(exp), 1e-12);
+    }
+}
+
+#[test]
+fn zero_base() {
+    let zero: Complex = Complex::new(0.0, 0.0);
+    assert_eq!(zero.pow(zero), Complex::REAL_UNIT);
+    assert_eq!(zero.pow(Complex::new(2.0, 5.0)), zero);
+    assert_eq!(zero.pow(Complex::with_real(-1.0)), Complex::with_real(f64::INFINITY));
+    assert!(zero.pow(Complex::new(-1.0, 1.0)).is_nan());
+}
+
+#[test]
+fn powi_is_exact_for_gaussian_integers() {
+    assert_eq!(Complex::new(1.0, 1.0).powi(4), Complex::new(-4.0, 0.0));
+    assert_eq!(Complex::new(1.0, 1.0).powi(-2), Complex::new(0.0, -0.5));
+    assert_eq!(Complex::new(3.0, 4.0).powi(5), Complex::new(-237.0, -3116.0));
+    assert_eq!(Complex::IMAG_UNIT.powi(1001), Complex::IMAG_UNIT);
+    assert_eq!(Complex::new(f64::NAN, 1.0).powi(0), Complex::REAL_UNIT);
+}
+
+#[test]
+fn powi_stays_on_unit_circle() {
+    let z: Complex = Complex::new(0.6, 0.8);
+    assert!((z.powi(1000).abs() - 1.0).abs() < 1e-12);
+    assert_close(z.powi(-1000), z.powi(1000).conj(), 1e-12);
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+
+use complex_proto::{Complex, Complex32, GaussianInt};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct State {
+    plain: Complex,
+    #[serde(with = "complex_proto::serde::fields")]
+    fields: Complex32,
+    #[serde(with = "complex_proto::serde::tuple")]
+    tuple: Complex,
+    #[serde(with = "complex_proto::serde::string")]
+    string: Complex,
+    #[serde(with = "complex_proto::serde::string")]
+    gaussian: GaussianInt,
+}
+
+#[test]
+fn json_formats() {
+    let state: State = State {
+        plain: Complex::new(3.0, 4.0),
+        fields: Complex32::new(0.5, -1.0),
+        tuple: Complex::new(-2.0, 0.25),
+        string: Complex::new(3.0, -4.5),
+        gaussian: GaussianInt::new(5, -7),
+    };
+    let json: String = serde_json::to_string(&state).unwrap();
+    assert_eq!(
+        json,
+        r#"{"plain":{"real":3.0,"imag":4.0},"fields":{"real":0.5,"imag":-1.0},"tuple":[-2.0,0.25],"string":"3-4.5i","gaussian":"5-7i"}"#
+    );
+    assert_eq!(serde_json::from_str::<State>(&json).unwrap(), state);
+}
+
+#[test]
+fn string_accepts_parse_forms() {
+    #[derive(Debug, Deserialize)]
+    struct Wrapper(#[serde(with = "complex_proto::serde::string")] Complex);
+    assert_eq!(serde_json::from_str::<Wrapper>(r#""(1, 2)""#).unwrap().0, Complex::new(1.0, 2.0));
+    assert_eq!(serde_json::from_str::<Wrapper>(r#""-i""#).unwrap().0, Complex::new(0.0, -1.0));
+    let err: serde_json::Error = serde_json::from_str::<Wrapper>(r#""1+xi""#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid imaginary part in complex number"), "{}", err);
+    assert!(serde_json::from_str::<Wrapper>("[1.0, 2.0]").is_err());
+}
+
+#[test]
+fn struct_accepts_sequence() {
+    assert_eq!(serde_json::from_str::<Complex>("[1.0, 2.0]").unwrap(), Complex::new(1.0, 2.0));
+}
@@ -0,0 +1,109 @@
+use complex_proto::simd::{self, Backend};
+use complex_proto::Complex;
+
+mod common;
+use common::Rng;
+
+const BACKENDS: [Backend; 3] = [Backend::Portable, Backend::Sse2, Backend::Avx2];
+const SPECIAL: [f64; 7] = [0.0, -0.0, 1.0, -2.5, f64::INFINITY, f64::NEG_INFINITY, 1e300];
+
+fn same(a: f64, b: f64) -> bool {
+    a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan())
+}
+
+fn assert_same(actual: &[Complex], expected: &[Complex], what: &str) {
+    assert_eq!(actual.len(), expected.len());
+    for (i, (x, y)) in actual.iter().zip(expected).enumerate() {
+        assert!(same(x.real, y.real) && same(x.imag, y.imag), "{}[{}]: {:?} != {:?}", what, i, x, y);
+    }
+}
+
+fn inputs(rng: &mut Rng, n: usize) -> Vec<Complex> {
+    (0..n)
+        .map(|_| match rng.next_u64() % 4 {
+            0 => Complex::new(SPECIAL[rng.next_u64() as usize % 7], SPECIAL[rng.next_u64() as usize % 7]),
+            _ => rng.complex(-1e3, 1e3),
+        })
+        .collect()
+}
+
+fn striped<T: Copy + std::ops::Add<Output = T>>(terms: impl Iterator<Item = T>, zero: T) -> T {
+    let mut partials: [T; 4] = [zero; 4];
+    for (i, t) in terms.enumerate() {
+        partials[i % 4] = partials[i % 4] + t;
+    }
+    (partials[0] + partials[2]) + (partials[1] + partials[3])
+}
+
+#[test]
+fn detect_is_available() {
+    assert!(Backend::detect().is_available());
+    assert!(Backend::Portable.is_available());
+}
+
+#[test]
+fn elementwise_matches_operators() {
+    let mut rng: Rng = Rng::new(19);
+    for n in (0..=11).chain([64, 1001]) {
+        let (a, b, c): (Vec<Complex>, Vec<Complex>, Vec<Complex>) = (inputs(&mut rng, n), inputs(&mut rng, n), inputs(&mut rng, n));
+        let prod: Vec<Complex> = a.iter().zip(&b).map(|(&x, &y)| x * y).collect();
+        let conj_prod: Vec<Complex> = a.iter().zip(&b).map(|(&x, &y)| x.conj() * y).collect();
+        let fma: Vec<Complex> = prod.iter().zip(&c).map(|(&p, &z)| z + p).collect();
+        let scaled: Vec<Complex> = a.iter().map(|&x| x * -0.75).collect();
+        for backend in BACKENDS.into_iter().filter(|b| b.is_available()) {
+            let mut out: Vec<Complex> = vec![Complex::default(); n];
+            backend.mul_slices(&a, &b, &mut out);
+            assert_same(&out, &prod, "mul_slices");
+            backend.conj_mul(&a, &b, &mut out);
+            assert_same(&out, &conj_prod, "conj_mul");
+            out.copy_from_slice(&c);
+            backend.mul_add(&a, &b, &mut out);
+            assert_same(&out, &fma, "mul_add");
+            out.copy_from_slice(&a);
+            backend.scale(&mut out, -0.75);
+            assert_same(&out, &scaled, "scale");
+        }
+    }
+}
+
+#[test]
+fn reductions_match_striped_sum() {
+    let mut rng: Rng = Rng::new(1919);
+    for n in (0..=11).chain([64, 1001]) {
+        let (a, b): (Vec<Complex>, Vec<Complex>) = (
+            (0..n).map(|_| rng.complex(-1.0, 1.0)).collect(),
+            (0..n).map(|_| rng.complex(-1.0, 1.0)).collect(),
+        );
+        let dot: Complex = striped(a.iter().zip(&b).map(|(&x, &y)| x * y), Complex::default());
+        let norm: f64 = striped(a.iter().map(|z| z.norm()), 0.0);
+        for backend in BACKENDS.into_iter().filter(|b| b.is_available()) {
+            assert_same(&[backend.dot(&a, &b)], &[dot], "dot");
+            assert!(same(backend.norm_sq_sum(&a), norm), "norm_sq_sum with {:?}", backend);
+        }
+        let naive: Complex = a.iter().zip(&b).map(|(&x, &y)| x * y).sum();
+        assert!(simd::dot(&a, &b).approx_eq(naive, 1e-12, 1e-12));
+    }
+}
+
+#[test]
+fn dispatching_functions() {
+    let a: Vec<Complex> = vec![Complex::new(1.0, 2.0), Complex::new(3.0, -1.0), Complex::new(0.5, 0.5)];
+    let b: Vec<Complex> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 2.0), Complex::new(-4.0, 0.0)];
+    let mut out: Vec<Complex> = vec![Complex::default(); 3];
+    simd::mul_slices(&a, &b, &mut out);
+    assert_eq!(out, vec![Complex::new(-2.0, 1.0), Complex::new(8.0, 4.0), Complex::new(-2.0, -2.0)]);
+    simd::conj_mul(&a, &b, &mut out);
+    assert_eq!(out[0], Complex::new(2.0, 1.0));
+    simd::mul_add(&a, &b, &mut out);
+    assert_eq!(out[0], Complex::new(0.0, 2.0));
+    simd::scale(&mut out, 2.0);
+    assert_eq!(out[0], Complex::new(0.0, 4.0));
+    assert_eq!(simd::dot(&a, &b), Complex::new(4.0, 3.0));
+    assert_eq!(simd::norm_sq_sum(&a), 15.5);
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn length_mismatch() {
+    simd::dot(&[Complex::default()], &[]);
+}
@@ -0,0 +1,311 @@
+use complex_proto::Complex;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+const PI: f64 = std::f64::consts::PI;
+const FRAC_PI_2: f64 = std::f64::consts::FRAC_PI_2;
+const FRAC_PI_4: f64 = std::f64::consts::FRAC_PI_4;
+const FRAC_3PI_4: f64 = PI - FRAC_PI_4;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Symmetry {
+    Odd,
+    Even,
+    None,
+}
+
+// Expected components may carry `S` to mark a sign that Annex G leaves unspecified.
+const S: bool = true;
+const E: bool = false;
+
+fn matches(actual: f64, expected: f64, any_sign: bool) -> bool {
+    if expected.is_nan() {
+        actual.is_nan()
+    } else if any_sign {
+        actual.abs() == expected.abs()
+    } else {
+        actual == expected && actual.is_sign_negative() == expected.is_sign_negative()
+    }
+}
+
+type Expected = (f64, bool, f64, bool);
+
+fn check_one(name: &str, f: fn(Complex) -> Complex, input: (f64, f64), expected: Expected) {
+    let w: Complex = f(Complex::new(input.0, input.1));
+    assert!(
+        matches(w.real, expected.0, expected.1) && matches(w.imag, expected.2, expected.3),
+        "{}({:?}, {:?}) = ({:?}, {:?}), expected ({:?}, {:?})",
+        name, input.0, input.1, w.real, w.imag, expected.0, expected.2,
+    );
+}
+
+fn check(name: &str, f: fn(Complex) -> Complex, symmetry: Symmetry, table: &[((f64, f64), Expected)]) {
+    for &((x, y), (u, su, v, sv)) in table {
+        check_one(name, f, (x, y), (u, su, v, sv));
+        check_one(name, f, (x, -y), (u, su, -v, sv));
+        match symmetry {
+            Symmetry::Odd => {
+                check_one(name, f, (-x, -y), (-u, su, -v, sv));
+                check_one(name, f, (-x, y), (-u, su, v, sv));
+            }
+            Symmetry::Even => {
+                check_one(name, f, (-x, -y), (u, su, v, sv));
+                check_one(name, f, (-x, y), (u, su, -v, sv));
+            }
+            Symmetry::None => {}
+        }
+    }
+}
+
+#[test]
+fn exp_special_values() {
+    check("exp", Complex::exp, Symmetry::None, &[
+        ((0.0, 0.0), (1.0, E, 0.0, E)),
+        ((-0.0, 0.0), (1.0, E, 0.0, E)),
+        ((1.0, INF), (NAN, E, NAN, E)),
+        ((1.0, NAN), (NAN, E, NAN, E)),
+        ((INF, 0.0), (INF, E, 0.0, E)),
+        ((-INF, 1.0), (1.0f64.cos() * 0.0, E, 1.0f64.sin() * 0.0, E)),
+        ((INF, 1.0), (INF, E, INF, E)),
+        ((-INF, INF), (0.0, S, 0.0, S)),
+        ((INF, INF), (INF, S, NAN, E)),
+        ((-INF, NAN), (0.0, S, 0.0, S)),
+        ((INF, NAN), (INF, S, NAN, E)),
+        ((NAN, 0.0), (NAN, E, 0.0, E)),
+        ((NAN, 1.0), (NAN, E, NAN, E)),
+        ((NAN, NAN), (NAN, E, NAN, E)),
+    ]);
+}
+
+#[test]
+fn ln_special_values() {
+    check("ln", Complex::ln, Symmetry::None, &[
+        ((-0.0, 0.0), (-INF, E, PI, E)),
+        ((0.0, 0.0), (-INF, E, 0.0, E)),
+        ((1.0, INF), (INF, E, FRAC_PI_2, E)),
+        ((1.0, NAN), (NAN, E, NAN, E)),
+        ((-INF, 1.0), (INF, E, PI, E)),
+        ((INF, 1.0), (INF, E, 0.0, E)),
+        ((-INF, INF), (INF, E, FRAC_3PI_4, E)),
+        ((INF, INF), (INF, E, FRAC_PI_4, E)),
+        ((INF, NAN), (INF, E, NAN, E)),
+        ((-INF, NAN), (INF, E, NAN, E)),
+        ((NAN, 1.0), (NAN, E, NAN, E)),
+        ((NAN, INF), (INF, E, NAN, E)),
+        ((NAN, NAN), (NAN, E, NAN, E)),
+    ]);
+}
+
+#[test]
+fn sqrt_special_values() {
+    check("sqrt", Complex::sqrt, Symmetry::None, &[
+        ((0.0, 0.0), (0.0, E, 0.0, E)),
+        ((-0.0, 0.0), (0.0, E, 0.0, E)),
+        ((1.0, INF), (INF, E, INF, E)),
+        ((-INF, INF), (INF, E, INF, E)),
+        ((NAN, INF), (INF, E, INF, E)),
+        ((1.0, NAN), (NAN, E, NAN, E)),
+        ((-INF, 1.0), (0.0, E, INF, E)),
+        ((INF, 1.0), (INF, E, 0.0, E)),
+        ((-INF, NAN), (NAN, E, INF, S)),
+        ((INF, NAN), (INF, E, NAN, E)),
+        ((NAN, 1.0), (NAN, E, NAN, E)),
+        ((NAN, NAN), (NAN, E, NAN, E)),
+    ]);
+}
+
+#[test]
+fn sinh_special_values() {
+    check("sinh", Complex::sinh, Symmetry::Odd, &[
+        ((0.0, 0.0), (0.0, E, 0.0, E)),
+        ((0.0, INF), (0.0, S, NAN, E)),
+        ((0.0, NAN), (0.0, S, NAN, E)),
+        ((INF, 0.0), (INF, E, 0.0, E)),
+        ((INF, 1.0), (INF, E, INF, E)),
+        ((INF, 2.0), (-INF, E, INF, E)),
+        ((INF, INF), (INF, S, NAN, E)),
+        ((INF, NAN), (INF, S, NAN, E)),
+        ((1.0, INF), (NAN, E, NAN, E)),
+        ((1.0, NAN), (NAN, E, NAN, E)),
+        ((NAN, 0.0), (NAN, E, 0.0, E)),
+        ((NAN, 1.0), (NAN, E, NAN, E)),
+        ((NAN, NAN), (NAN, E, NAN, E)),
+    ]);
+}
+
+#[test]
+fn cosh_special_values() {
+    check("cosh", Complex::cosh, Symmetry::Even, &[
+        ((0.0, 0.0), (1.0, E, 0.0, E)),
+        ((0.0, INF), (NAN, E, 0.0, S)),
+        ((0.0, NAN), (NAN, E, 0.0, S)),
+        ((1.0, INF), (NAN, E, NAN, E)),
+        ((1.0, NAN), (NAN, E, NAN, E)),
+        ((INF, 0.0), (INF, E, 0.0, E)),
+        ((INF, 1.0), (INF, E, INF, E)),
+        ((INF, 2.0), (-INF, E, INF, E)),
+        ((INF, INF), (INF, S, NAN, E)),
+        ((INF, NAN), (INF, E, NAN, E)),
+        ((NAN, 0.0), (NAN, E, 0.0, S)),
+        ((NAN, 1.0), (NAN, E, NAN, E)),
+        ((NAN, NAN), (NAN, E, NAN, E)),
+    ]);
+}
+
+#[test]
+fn tanh_special_values() {
+    check("tanh", Complex::tanh, Symmetry::Odd, &[
+        ((0.0, 0.0), (0.0, E, 0.0, E)),
+        ((0.0, INF), (0.0, E, NAN, E)),
+        ((1.0, INF), (NAN, E, NAN, E)),
+        ((0.0, NAN), (0.0, E, NAN, E)),
+        ((1.0, NAN), (NAN, E, NAN, E)),
+        ((INF, 1.0), (1.0, E, 0.0, E)),
+        ((INF, 2.0), (1.0, E, -0.0, E)),
+        ((INF, INF), (1.0, E, 0.0, S)),
+        ((INF, NAN), (1.0, E, 0.0, S)),
+        ((NAN, 0.0), (NAN, E, 0.0, E)),
+        ((NAN, 1.0), (NAN, E, NAN, E)),
+        ((NAN, NAN), (NAN, E, NAN, E)),
+    ]);
+}
+
+#[test]
+fn asinh_special_values() {
+    check("asinh", Complex::asinh, Symmetry::Odd, &[
+        ((0.0, 0.0), (0.0, E, 0.0, E)),
+        ((1.0, INF), (INF, E, FRAC_PI_2, E)),
+        ((1.0, NAN), (NAN, E, NAN, E)),
+        ((INF, 1.0), (INF, E, 0.0, E)),
+        ((INF, INF), (INF, E, FRAC_PI_4, E)),
+        ((INF, NAN), (INF, E, NAN, E)),
+        ((NAN, 0.0), (NAN, E, 0.0, E)),
+        ((NAN, 1.0), (NAN, E, NAN, E)),
+        ((NAN, INF), (INF, S, NAN, E)),
+        ((NAN, NAN), (NAN, E, NAN, E)),
+    ]);
+}
+
+#[test]
+fn acosh_special_values() {
+    check("acosh", Complex::acosh, Symmetry::None, &[
+        ((0.0, 0.0), (0.0, E, FRAC_PI_2, E)),
+        ((-0.0, 0.0), (0.0, E, FRAC_PI_2, E)),
+        ((1.0, INF), (INF, E, FRAC_PI_2, E)),
+        ((0.0, NAN), (NAN, E, NAN, E)),
+        ((1.0, NAN), (NAN, E, NAN, E)),
+        ((-INF, 1.0), (INF, E, PI, E)),
+        ((INF, 1.0), (INF, E, 0.0, E)),
+        ((-INF, INF), (INF, E, FRAC_3PI_4, E)),
+        ((INF, INF), (INF, E, FRAC_PI_4, E)),
+        ((INF, NAN), (INF, E, NAN, E)),
+        ((-INF, NAN), (INF, E, NAN, E)),
+        ((NAN, 1.0), (NAN, E, NAN, E)),
+        ((NAN, INF), (INF, E, NAN, E)),
+        ((NAN, NAN), (NAN, E, NAN, E)),
+    ]);
+}
+
+#[test]
+fn atanh_special_values() {
+    check("atanh", Complex::atanh, Symmetry::Odd, &[
+        ((0.0, 0.0), (0.0, E, 0.0, E)),
+        ((0.0, NAN), (0.0, E, NAN, E)),
+        ((1.0, 0.0), (INF, E, 0.0, E)),
+        ((1.0, INF), (0.0, E, FRAC_PI_2, E)),
+        ((1.0, NAN), (NAN, E, NAN, E)),
+        ((INF, 1.0), (0.0, E, FRAC_PI_2, E)),
+        ((INF, INF), (0.0, E, FRAC_PI_2, E)),
+        ((INF, NAN), (0.0, E, NAN, E)),
+        ((NAN, 1.0), (NAN, E, NAN, E)),
+        ((NAN, INF), (0.0, S, FRAC_PI_2, E)),
+        ((NAN, NAN), (NAN, E, NAN, E)),
+    ]);
+}
+
+#[test]
+fn acos_special_values() {
+    check("acos", Complex::acos, Symmetry::None, &[
+        ((0.0, 0.0), (FRAC_PI_2, E, -0.0, E)),
+        ((-0.0, 0.0), (FRAC_PI_2, E, -0.0, E)),
+        ((0.0, NAN), (FRAC_PI_2, E, NAN, E)),
+        ((-0.0, NAN), (FRAC_PI_2, E, NAN, E)),
+        ((1.0, INF), (FRAC_PI_2, E, -INF, E)),
+        ((1.0, NAN), (NAN, E, NAN, E)),
+        ((-INF, 1.0), (PI, E, -INF, E)),
+        ((INF, 1.0), (0.0, E, -INF, E)),
+        ((-INF, INF), (FRAC_3PI_4, E, -INF, E)),
+        ((INF, INF), (FRAC_PI_4, E, -INF, E)),
+        ((INF, NAN), (NAN, E, INF, S)),
+        ((-INF, NAN), (NAN, E, INF, S)),
+        ((NAN, 1.0), (NAN, E, NAN, E)),
+        ((NAN, INF), (NAN, E, -INF, E)),
+        ((NAN, NAN), (NAN, E, NAN, E)),
+    ]);
+}
+
+fn via_hyperbolic(f: fn(Complex) -> Complex, g: fn(Complex) -> Complex, mul_result_by_neg_i: bool) {
+    let values: [f64; 8] = [0.0, -0.0, 1.0, -2.0, INF, -INF, NAN, 0.5];
+    for &x in values.iter() {
+        for &y in values.iter() {
+            let w: Complex = g(Complex::new(-y, x));
+            let expected: Complex = if mul_result_by_neg_i { Complex::new(w.imag, -w.real) } else { w };
+            check_one("derived", f, (x, y), (expected.real, E, expected.imag, E));
+        }
+    }
+}
+
+#[test]
+fn trig_special_values_follow_hyperbolic() {
+    via_hyperbolic(Complex::sin, Complex::sinh, true);
+    via_hyperbolic(Complex::cos, Complex::cosh, false);
+    via_hyperbolic(|z| z.tan(), Complex::tanh, true);
+    via_hyperbolic(Complex::asin, Complex::asinh, true);
+    via_hyperbolic(Complex::atan, Complex::atanh, true);
+}
+
+#[test]
+fn large_real_part_does_not_overflow_early() {
+    let z: Complex = Complex::new(709.9, 0.8).exp();
+    assert!(z.real.is_finite() && z.imag.is_finite());
+    let z: Complex = Complex::new(-710.6, 1.0).cosh();
+    assert!(z.real.is_finite() && z.imag.is_finite() && z.imag < 0.0);
+    let z: Complex = Complex::new(710.6, 1.0).sinh();
+    assert!(z.real.is_finite() && z.imag.is_finite());
+}
+
+#[test]
+fn large_arguments_keep_tan_and_tanh_finite() {
+    assert_eq!(Complex::new(800.0, 1.0).tanh(), Complex::new(1.0, 0.0));
+    assert_eq!(Complex::new(-1e300, 0.5).tanh(), Complex::new(-1.0, 0.0));
+    assert_eq!(Complex::new(1.0, 800.0).tan(), Complex::new(0.0, 1.0));
+    assert_eq!(Complex::new(1.0, -1e300).tan(), Complex::new(0.0, -1.0));
+    assert!(Complex::new(2.0, -800.0).tan().real.is_sign_negative());
+    let cases: [((f64, f64), (f64, f64)); 5] = [
+        ((19.0, 0.7), (1.0, 6.186915124643304e-17)),
+        ((21.0, 1.0), (1.0, 1.0456051600798202e-18)),
+        ((30.0, -2.0), (1.0, 1.3253898390798914e-26)),
+        ((0.5, FRAC_PI_2), (2.163953413738653, 2.254999940412124e-16)),
+        ((5.0, 1e10), (0.9999523576630074, -7.729443343429309e-05)),
+    ];
+    for ((x, y), (u, v)) in cases {
+        let w: Complex = Complex::new(x, y).tanh();
+        assert!((w.real - u).abs() <= 2.0 * f64::EPSILON * u.abs(), "tanh({}, {}) = {:?}", x, y, w);
+        assert!((w.imag - v).abs() <= 4.0 * f64::EPSILON * v.abs(), "tanh({}, {}) = {:?}", x, y, w);
+        let t: Complex = Complex::new(-y, x).tan();
+        assert_eq!(t, Complex::new(-w.imag, w.real));
+    }
+}
+
+#[test]
+fn ln_1p_and_exp_m1_edges() {
+    assert_eq!(Complex::new(-1.0, 0.0).ln_1p(), Complex::new(-INF, 0.0));
+    assert_eq!(Complex::new(INF, 1.0).ln_1p(), Complex::new(INF, 0.0));
+    assert!(Complex::new(-0.0, -0.0).ln_1p().imag.is_sign_negative());
+    assert_eq!(Complex::new(-INF, 0.0).exp_m1(), Complex::new(-1.0, 0.0));
+    assert_eq!(Complex::new(800.0, 0.0).exp_m1(), Complex::new(INF, 0.0));
+    assert!(Complex::new(-0.0, 0.0).exp_m1().real.is_sign_negative());
+    assert!(Complex::new(NAN, 1.0).exp_m1().is_nan());
+    assert_eq!(Complex::new(1.0, 0.0).ln(), Complex::new(0.0, 0.0));
+    assert_eq!(Complex::new(-1.0, -0.0).ln(), Complex::new(0.0, -PI));
+}
@@ -0,0 +1,63 @@
+use complex_proto::{Complex, SplitComplex};
+
+mod common;
+use common::{assert_bits, Rng};
+
+type Check = (SplitComplex, fn(Complex, Complex) -> Complex);
+
+fn random(rng: &mut Rng, n: usize) -> Vec<Complex> {
+    (0..n).map(|_| rng.complex(-10.0, 10.0)).collect()
+}
+
+#[test]
+fn conversions() {
+    let zs: Vec<Complex> = vec![Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5)];
+    let split: SplitComplex = SplitComplex::from(zs.clone());
+    assert_eq!(split.real(), &[1.0, -3.0]);
+    assert_eq!(split.imag(), &[2.0, 0.5]);
+    assert_eq!(split.len(), 2);
+    assert_eq!(split.get(1), Some(Complex::new(-3.0, 0.5)));
+    assert_eq!(split.get(2), None);
+    assert_eq!(Vec::<Complex>::from(split.clone()), zs);
+    assert_eq!(split.iter().collect::<SplitComplex>(), split);
+    assert_eq!(SplitComplex::from_parts(vec![1.0, -3.0], vec![2.0, 0.5]), Some(split));
+    assert_eq!(SplitComplex::<f64>::from_parts(vec![1.0], vec![]), None);
+    assert!(SplitComplex::<f64>::new().is_empty());
+}
+
+#[test]
+fn matches_scalar_path() {
+    let mut rng: Rng = Rng::new(18);
+    let (a, b): (Vec<Complex>, Vec<Complex>) = (random(&mut rng, 257), random(&mut rng, 257));
+    let (sa, sb): (SplitComplex, SplitComplex) = (SplitComplex::from(a.as_slice()), SplitComplex::from(b.as_slice()));
+    let checks: [Check; 4] = [
+        (&sa + &sb, |x, y| x + y),
+        (&sa - &sb, |x, y| x - y),
+        (&sa * &sb, |x, y| x * y),
+        (&sa / &sb, |x, y| x / y),
+    ];
+    for (out, op) in checks.iter() {
+        for ((z, &x), &y) in out.iter().zip(&a).zip(&b) {
+            assert_bits(z, op(x, y));
+        }
+    }
+    for ((z, &x), (&abs, &norm)) in sa.clone().exp().iter().zip(&a).zip(sa.abs().iter().zip(&sa.norm())) {
+        assert_bits(z, x.exp());
+        assert_eq!(abs, x.abs());
+        assert_eq!(norm, x.norm());
+    }
+    for (z, &x) in sa.clone().conj().iter().zip(&a) {
+        assert_bits(z, x.conj());
+    }
+    let mut acc: SplitComplex = sa.clone();
+    acc *= &sb;
+    acc += &sa;
+    assert_eq!(acc, sa.clone() * sb.clone() + sa);
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn length_mismatch() {
+    let a: SplitComplex = SplitComplex::from(vec![Complex::new(1.0, 0.0)]);
+    let _ = &a + &SplitComplex::new();
+}
@@ -0,0 +1,49 @@
+use complex_proto::Complex;
+
+mod common;
+use common::{assert_bits, assert_close, Rng};
+
+#[test]
+fn exact_on_axes() {
+    assert_bits(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+    assert_bits(Complex::new(-4.0, -0.0).sqrt(), Complex::new(0.0, -2.0));
+    assert_bits(Complex::new(9.0, 0.0).sqrt(), Complex::new(3.0, 0.0));
+    assert_bits(Complex::new(9.0, -0.0).sqrt(), Complex::new(3.0, -0.0));
+    assert_bits(Complex::new(0.0, 2.0).sqrt(), Complex::new(1.0, 1.0));
+    assert_bits(Complex::new(0.0, -8.0).sqrt(), Complex::new(2.0, -2.0));
+}
+
+#[test]
+fn exact_for_perfect_squares() {
+    for a in -20..=20 {
+        for b in -20..=20 {
+            let (a, b): (i32, i32) = if a < 0 || (a == 0 && b < 0) { (-a, -b) } else { (a, b) };
+            let root: Complex = Complex::new(a as f64, b as f64);
+            assert_eq!((root * root).sqrt(), root);
+        }
+    }
+}
+
+#[test]
+fn no_intermediate_overflow_or_underflow() {
+    let max: f64 = f64::MAX;
+    let w: Complex = Complex::new(max, max).sqrt();
+    assert!(w.real.is_finite() && w.imag.is_finite());
+    assert_close(w, Complex::new(1.4730945569055655e154, 6.101757441282702e153), 1e-15);
+    let w: Complex = Complex::new(-max, 0.0).sqrt();
+    assert_eq!(w, Complex::new(0.0, max.sqrt()));
+    let w: Complex = Complex::new(0.0, 5e-324).sqrt();
+    assert!(w.real > 0.0 && w.imag > 0.0);
+    assert_close(w * w, Complex::new(0.0, 5e-324), 1e-15);
+}
+
+#[test]
+fn squares_back() {
+    let mut rng: Rng = Rng::new(5);
+    for _ in 0..10000 {
+        let z: Complex = rng.complex(-1e6, 1e6);
+        let w: Complex = z.sqrt();
+        assert!(w.real >= 0.0);
+        assert_close(w * w, z, 1e-14);
+    }
+}